@@ -97,6 +97,9 @@ pub enum BinaryOperator {
     /// Numeric division
     Division,
 
+    /// Integer modulo, with a remainder that is never negative
+    Modulo,
+
     /// Logical And
     And,
 
@@ -132,6 +135,8 @@ pub enum AggregationOperator {
     Sum,
     /// Count
     Count,
+    /// Count of distinct values
+    CountDistinct,
     /// Return the first value
     First,
 }
@@ -143,6 +148,7 @@ impl Display for AggregationOperator {
             AggregationOperator::Min => write!(f, "min"),
             AggregationOperator::Sum => write!(f, "sum"),
             AggregationOperator::Count => write!(f, "count"),
+            AggregationOperator::CountDistinct => write!(f, "count_distinct"),
             AggregationOperator::First => write!(f, "first"),
         }
     }
@@ -175,6 +181,24 @@ pub enum Expression {
         right: Box<Expression>,
     },
 
+    /// `expr IN (list)` operation
+    InList {
+        /// The expression to look up in the list
+        expr: Box<Expression>,
+        /// The literals the expression is compared against
+        list: Vec<Literal>,
+    },
+
+    /// `CASE WHEN predicate THEN then ELSE otherwise END` operation
+    Case {
+        /// The boolean expression choosing between the branches
+        predicate: Box<Expression>,
+        /// The value where the predicate holds
+        then: Box<Expression>,
+        /// The value where the predicate does not hold
+        otherwise: Box<Expression>,
+    },
+
     /// * expression
     Wildcard,
 
@@ -224,6 +248,15 @@ impl Expression {
         })
     }
 
+    /// Create a new `COUNT(DISTINCT)`
+    #[must_use]
+    pub fn count_distinct(self) -> Box<Self> {
+        Box::new(Expression::Aggregation {
+            op: AggregationOperator::CountDistinct,
+            expr: Box::new(self),
+        })
+    }
+
     /// Create a new `FIRST()`
     #[must_use]
     pub fn first(self) -> Box<Self> {
@@ -1,5 +1,8 @@
 use crate::{
-    intermediate_ast::OrderByDirection::{Asc, Desc},
+    intermediate_ast::{
+        Literal,
+        OrderByDirection::{Asc, Desc},
+    },
     sql::*,
     utility::*,
     SelectStatement,
@@ -404,6 +407,149 @@ fn we_can_parse_a_query_with_one_logical_and_filter_expression() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_query_with_a_between_filter_expression() {
+    let ast = "select a from sxt_tab where b BETWEEN 1 and 5"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "sxt_tab"),
+            and(ge(col("b"), lit(1)), le(col("b"), lit(5))),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_between_with_arithmetic_bounds_and_lower_precedence_logical_operators() {
+    let ast = "select a from sxt_tab where b between c - 1 and 5 and not d or e"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "sxt_tab"),
+            or(
+                and(
+                    and(ge(col("b"), sub(col("c"), lit(1))), le(col("b"), lit(5))),
+                    not(col("d")),
+                ),
+                col("e"),
+            ),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_with_an_in_list_filter_expression() {
+    let ast = "select a from sxt_tab where b IN (1, -3, 7)"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "sxt_tab"),
+            in_list(
+                col("b"),
+                vec![Literal::BigInt(1), Literal::BigInt(-3), Literal::BigInt(7)],
+            ),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_in_lists_with_arithmetic_and_lower_precedence_logical_operators() {
+    let ast = "select a from sxt_tab where not c + 1 in ('x') and d in (true, false)"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "sxt_tab"),
+            and(
+                not(in_list(
+                    add(col("c"), lit(1)),
+                    vec![Literal::VarChar("x".to_string())],
+                )),
+                in_list(
+                    col("d"),
+                    vec![Literal::Boolean(true), Literal::Boolean(false)],
+                ),
+            ),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_with_a_case_when_result_expression() {
+    let ast = "select case when a > 0 then a * 2 else 0 end as b from sxt_tab"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![col_res(
+                case_when(not(le(col("a"), lit(0))), mul(col("a"), lit(2)), lit(0)),
+                "b",
+            )],
+            tab(None, "sxt_tab"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_nested_case_when_expressions_case_insensitively() {
+    let ast =
+        "select a from sxt_tab where CASE WHEN b THEN CASE when c then d else e END ELSE f end"
+            .parse::<SelectStatement>()
+            .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "sxt_tab"),
+            case_when(col("b"), case_when(col("c"), col("d"), col("e")), col("f")),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_a_case_when_expression_without_an_else_branch() {
+    assert!("select case when a then b end as c from sxt_tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+#[test]
+fn we_cannot_parse_an_empty_in_list() {
+    assert!("select a from sxt_tab where b in ()"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
 #[test]
 fn we_can_parse_a_query_with_one_logical_and_filter_expression_with_both_left_and_right_side_equal_to_string_literals(
 ) {
@@ -1171,6 +1317,27 @@ fn we_can_parse_a_group_by_clause_containing_multiple_aggregations() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_count_distinct() {
+    let ast = "select a, COUNT(DISTINCT b), count(distinct c) as n from tab group by a"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![
+                col_res(col("a"), "a"),
+                count_distinct_res(col("b"), "__count_distinct__"),
+                count_distinct_res(col("c"), "n"),
+            ],
+            tab(None, "tab"),
+            group_by(&["a"]),
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
 #[test]
 fn we_can_parse_a_group_by_clause_containing_multiple_aggregations_where_clause_order_by_and_limit()
 {
@@ -1362,6 +1529,27 @@ fn we_can_parse_multiple_arithmetic_expression_where_multiplication_has_preceden
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_modulo_with_the_same_precedence_as_multiplication() {
+    let ast = "select a % 3 * b, c + d % 2 from tab where e % 100 = 0"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![
+                col_res(modulo(col("a"), lit(3)) * col("b"), "__expr__"),
+                col_res(col("c") + modulo(col("d"), lit(2)), "__expr__"),
+            ],
+            tab(None, "tab"),
+            equal(modulo(col("e"), lit(100)), lit(0)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
 #[test]
 fn we_can_parse_arithmetic_expression_within_aggregations_in_the_result_expr() {
     let ast = "select sum(2 * f + c) as d from tab"
@@ -116,6 +116,36 @@ pub fn div(left: Box<Expression>, right: Box<Expression>) -> Box<Expression> {
     })
 }
 
+/// Construct a new boxed `Expression` A % B
+#[must_use]
+pub fn modulo(left: Box<Expression>, right: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::Binary {
+        op: BinaryOperator::Modulo,
+        left,
+        right,
+    })
+}
+
+/// Construct a new boxed `Expression` A IN (B, C, ...)
+#[must_use]
+pub fn in_list(expr: Box<Expression>, list: Vec<Literal>) -> Box<Expression> {
+    Box::new(Expression::InList { expr, list })
+}
+
+/// Construct a new boxed `Expression` CASE WHEN P THEN A ELSE B END
+#[must_use]
+pub fn case_when(
+    predicate: Box<Expression>,
+    then: Box<Expression>,
+    otherwise: Box<Expression>,
+) -> Box<Expression> {
+    Box::new(Expression::Case {
+        predicate,
+        then,
+        otherwise,
+    })
+}
+
 /// Get table from schema and name.
 ///
 /// If the schema is `None`, the table is assumed to be in the default schema.
@@ -181,6 +211,15 @@ pub fn count(expr: Box<Expression>) -> Box<Expression> {
     })
 }
 
+/// Count the distinct values of expression
+#[must_use]
+pub fn count_distinct(expr: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::Aggregation {
+        op: AggregationOperator::CountDistinct,
+        expr,
+    })
+}
+
 /// Count the rows
 #[must_use]
 pub fn count_all() -> Box<Expression> {
@@ -277,6 +316,19 @@ pub fn count_res(expr: Box<Expression>, alias: &str) -> SelectResultExpr {
     })
 }
 
+/// Count distinct values and give the result an alias i.e. SELECT COUNT(DISTINCT EXPR) AS ALIAS
+///
+/// # Panics
+///
+/// This function will panic if the `alias` cannot be parsed.
+#[must_use]
+pub fn count_distinct_res(expr: Box<Expression>, alias: &str) -> SelectResultExpr {
+    SelectResultExpr::AliasedResultExpr(AliasedResultExpr {
+        expr: count_distinct(expr),
+        alias: alias.parse().unwrap(),
+    })
+}
+
 /// Count rows and give the result an alias i.e. SELECT COUNT(*) AS ALIAS
 ///
 /// # Panics
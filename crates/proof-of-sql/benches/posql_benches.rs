@@ -6,9 +6,10 @@
 #![allow(missing_docs, clippy::missing_docs_in_private_items)]
 use blitzar::proof::InnerProductProof;
 use criterion::{criterion_group, criterion_main, Criterion};
+use proof_of_sql::base::database::ColumnType;
 
 mod scaffold;
-use scaffold::{criterion_scaffold, querys::QUERIES};
+use scaffold::{criterion_scaffold, querys::QUERIES, OptionalRandBound};
 
 const SIZES: &[usize] = &[
     1,
@@ -36,5 +37,36 @@ fn all_benches(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, all_benches);
+const WIDE_RESULT_WIDTHS: &[usize] = &[1, 10, 100];
+const WIDE_RESULT_SIZES: &[usize] = &[1_000, 10_000, 100_000];
+
+/// Measure how proof generation and verification scale with the number of result columns.
+#[allow(clippy::cast_possible_wrap)]
+fn wide_result_benches(c: &mut Criterion) {
+    for &width in WIDE_RESULT_WIDTHS {
+        let names: Vec<String> = (0..width).map(|i| format!("c{i}")).collect();
+        let mut columns: Vec<(&str, ColumnType, OptionalRandBound)> = vec![(
+            "a",
+            ColumnType::BigInt,
+            Some(|size| (size / 10).max(10) as i64),
+        )];
+        columns.extend(
+            names
+                .iter()
+                .map(|name| (name.as_str(), ColumnType::BigInt, None)),
+        );
+        let query = format!("SELECT {} FROM table WHERE a = 0", names.join(", "));
+        criterion_scaffold::<InnerProductProof>(
+            c,
+            &format!("Wide Result ({width} Columns)"),
+            &query,
+            &columns,
+            WIDE_RESULT_SIZES,
+            &(),
+            &(),
+        );
+    }
+}
+
+criterion_group!(benches, all_benches, wide_result_benches);
 criterion_main!(benches);
@@ -5,12 +5,18 @@ use proof_of_sql::{
     base::{commitment::CommitmentEvaluationProof, database::ColumnType},
     sql::{parse::QueryExpr, proof::VerifiableQueryResult},
 };
-use rand::prelude::Rng;
+use rand::{prelude::Rng, rngs::StdRng, SeedableRng};
 mod benchmark_accessor;
 use benchmark_accessor::BenchmarkAccessor;
 pub mod querys;
 mod random_util;
-use random_util::{generate_random_columns, OptionalRandBound};
+use random_util::generate_random_columns;
+pub use random_util::OptionalRandBound;
+
+/// The seed of the random number generator the benchmark tables are generated with.
+///
+/// Fixing the seed makes every run benchmark identical tables, so runs can be compared.
+const SEED: [u8; 32] = [0; 32];
 
 /// # Panics
 ///
@@ -48,6 +54,7 @@ fn scaffold<'a, CP: CommitmentEvaluationProof>(
 /// Will panic if:
 /// - The call to `scaffold` results in a panic due to invalid inputs.
 /// - The `verify` method of `VerifiableQueryResult` fails, indicating an invalid proof.
+#[allow(dead_code, clippy::module_name_repetitions)]
 pub fn jaeger_scaffold<CP: CommitmentEvaluationProof>(
     title: &str,
     query: &str,
@@ -57,7 +64,7 @@ pub fn jaeger_scaffold<CP: CommitmentEvaluationProof>(
     verifier_setup: &CP::VerifierPublicSetup<'_>,
 ) {
     let mut accessor = BenchmarkAccessor::default();
-    let mut rng = rand::thread_rng();
+    let mut rng = StdRng::from_seed(SEED);
     let alloc = Bump::new();
     let (query, result) = scaffold::<CP>(
         query,
@@ -88,7 +95,7 @@ pub fn criterion_scaffold<CP: CommitmentEvaluationProof>(
     group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
     init_backend();
     let mut accessor = BenchmarkAccessor::default();
-    let mut rng = rand::thread_rng();
+    let mut rng = StdRng::from_seed(SEED);
     let alloc = Bump::new();
     for &size in sizes {
         group.throughput(criterion::Throughput::Elements(size as u64));
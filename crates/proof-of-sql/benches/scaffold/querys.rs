@@ -43,6 +43,33 @@ const ARITHMETIC_COLUMNS: &[(&str, ColumnType, OptionalRandBound)] = &[
     ),
     ("c", ColumnType::VarChar, None),
 ];
+const GROUP_BY_TITLE: &str = "Group By";
+const GROUP_BY_SQL: &str =
+    "SELECT a, sum(b) as s, count(*) as n FROM table WHERE c = 'a' group by a";
+const GROUP_BY_COLUMNS: &[(&str, ColumnType, OptionalRandBound)] = &[
+    (
+        "a",
+        ColumnType::BigInt,
+        Some(|size| (size / 10).max(10) as i64),
+    ),
+    (
+        "b",
+        ColumnType::BigInt,
+        Some(|size| (size / 10).max(10) as i64),
+    ),
+    ("c", ColumnType::VarChar, Some(|_| 1)),
+];
+// The group column only takes five distinct values, so every group has many duplicates.
+const SKEWED_GROUP_BY_TITLE: &str = "Skewed Group By";
+const SKEWED_GROUP_BY_SQL: &str = "SELECT a, sum(b) as s, count(*) as n FROM table group by a";
+const SKEWED_GROUP_BY_COLUMNS: &[(&str, ColumnType, OptionalRandBound)] = &[
+    ("a", ColumnType::BigInt, Some(|_| 2)),
+    (
+        "b",
+        ColumnType::BigInt,
+        Some(|size| (size / 10).max(10) as i64),
+    ),
+];
 
 #[allow(clippy::type_complexity)]
 pub const QUERIES: &[(&str, &str, &[(&str, ColumnType, OptionalRandBound)])] = &[
@@ -57,4 +84,10 @@ pub const QUERIES: &[(&str, &str, &[(&str, ColumnType, OptionalRandBound)])] = &
         MULTI_COLUMN_FILTER_COLUMNS,
     ),
     (ARITHMETIC_TITLE, ARITHMETIC_SQL, ARITHMETIC_COLUMNS),
+    (GROUP_BY_TITLE, GROUP_BY_SQL, GROUP_BY_COLUMNS),
+    (
+        SKEWED_GROUP_BY_TITLE,
+        SKEWED_GROUP_BY_SQL,
+        SKEWED_GROUP_BY_COLUMNS,
+    ),
 ];
@@ -41,6 +41,13 @@ impl<C: Commitment> CommitmentAccessor<C> for CommitAccessor<C> {
     fn get_commitment(&self, column: proof_of_sql::base::database::ColumnRef) -> C {
         self.inner.get_commitment(column)
     }
+
+    fn try_get_commitment(
+        &self,
+        column: proof_of_sql::base::database::ColumnRef,
+    ) -> Result<C, proof_of_sql::base::proof::ProofError> {
+        self.inner.try_get_commitment(column)
+    }
 }
 impl<C: Commitment> MetadataAccessor for CommitAccessor<C> {
     fn get_length(&self, table_ref: proof_of_sql::base::database::TableRef) -> usize {
@@ -50,6 +57,13 @@ impl<C: Commitment> MetadataAccessor for CommitAccessor<C> {
     fn get_offset(&self, table_ref: proof_of_sql::base::database::TableRef) -> usize {
         self.inner.get_offset(table_ref)
     }
+
+    fn try_get_length(
+        &self,
+        table_ref: proof_of_sql::base::database::TableRef,
+    ) -> Result<usize, proof_of_sql::base::proof::ProofError> {
+        self.inner.try_get_length(table_ref)
+    }
 }
 impl<C: Commitment> SchemaAccessor for CommitAccessor<C> {
     fn lookup_column(
@@ -92,7 +92,12 @@ impl CommitmentEvaluationProof for InnerProductProof {
         generators_offset: u64,
         _setup: &Self::ProverPublicSetup<'_>,
     ) -> Self {
-        assert!(!a.is_empty());
+        // An empty `a` is proven as the single entry zero, which has the same commitment.
+        let a = if a.is_empty() {
+            &[Self::Scalar::ZERO][..]
+        } else {
+            a
+        };
         let b = &mut vec![MontScalar::default(); a.len()];
         if b_point.is_empty() {
             assert_eq!(b.len(), 1);
@@ -123,7 +128,8 @@ impl CommitmentEvaluationProof for InnerProductProof {
         table_length: usize,
         _setup: &Self::VerifierPublicSetup<'_>,
     ) -> Result<(), Self::Error> {
-        assert!(table_length > 0);
+        // An empty table is verified as a single zero entry, matching `new`.
+        let table_length = table_length.max(1);
         let b = &mut vec![MontScalar::default(); table_length];
         if b_point.is_empty() {
             assert_eq!(b.len(), 1);
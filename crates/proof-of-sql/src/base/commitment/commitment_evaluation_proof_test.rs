@@ -57,6 +57,43 @@ pub fn test_commitment_evaluation_proof_with_length_1<CP: CommitmentEvaluationPr
     assert!(r.is_ok());
 }
 
+pub fn test_commitment_evaluation_proof_with_length_0<CP: CommitmentEvaluationProof>(
+    prover_setup: &CP::ProverPublicSetup<'_>,
+    verifier_setup: &CP::VerifierPublicSetup<'_>,
+) {
+    let mut rng = ark_std::test_rng();
+    let b_point = [CP::Scalar::rand(&mut rng)];
+    let mut transcript = Transcript::new(b"evaluation_proof");
+    let proof = CP::new(&mut transcript, &[], &b_point, 0, prover_setup);
+
+    let commits =
+        Vec::from_columns_with_offset([Column::<CP::Scalar>::Scalar(&[])], 0, prover_setup);
+
+    let mut transcript = Transcript::new(b"evaluation_proof");
+    let r = proof.verify_proof(
+        &mut transcript,
+        &commits[0],
+        &CP::Scalar::zero(),
+        &b_point,
+        0,
+        0,
+        verifier_setup,
+    );
+    assert!(r.is_ok());
+
+    let mut transcript = Transcript::new(b"evaluation_proof");
+    let r = proof.verify_proof(
+        &mut transcript,
+        &commits[0],
+        &CP::Scalar::one(),
+        &b_point,
+        0,
+        0,
+        verifier_setup,
+    );
+    assert!(r.is_err());
+}
+
 pub fn test_random_commitment_evaluation_proof<CP: CommitmentEvaluationProof>(
     table_length: usize,
     offset: usize,
@@ -149,6 +186,12 @@ fn test_random_ipa_with_length_1() {
     test_commitment_evaluation_proof_with_length_1::<InnerProductProof>(&(), &());
 }
 
+#[test]
+#[cfg(feature = "blitzar")]
+fn test_ipa_with_length_0() {
+    test_commitment_evaluation_proof_with_length_0::<InnerProductProof>(&(), &());
+}
+
 #[test]
 #[cfg(feature = "blitzar")]
 fn test_random_ipa_with_length_128() {
@@ -5,8 +5,9 @@ use crate::base::{
         TableRef,
     },
     map::IndexMap,
+    proof::ProofError,
 };
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 use proof_of_sql_parser::Identifier;
 
 /// The commitments for all of the tables in a query.
@@ -65,31 +66,52 @@ impl<C: Commitment> QueryCommitmentsExt<C> for QueryCommitments<C> {
     }
 }
 
+/// # Panics
+///
+/// Panics if there is no commitment for the table. Use [`MetadataAccessor::try_get_length`] to
+/// get an error instead.
 impl<C: Commitment> MetadataAccessor for QueryCommitments<C> {
-    fn get_length(&self, table_ref: crate::base::database::TableRef) -> usize {
-        let table_commitment = self.get(&table_ref).unwrap();
-
-        table_commitment.num_rows()
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.try_get_length(table_ref)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 
-    fn get_offset(&self, table_ref: crate::base::database::TableRef) -> usize {
+    fn get_offset(&self, table_ref: TableRef) -> usize {
         let table_commitment = self.get(&table_ref).unwrap();
 
         table_commitment.range().start
     }
+
+    fn try_get_length(&self, table_ref: TableRef) -> Result<usize, ProofError> {
+        self.get(&table_ref)
+            .map(TableCommitment::num_rows)
+            .ok_or_else(|| ProofError::MissingTableCommitment {
+                table: table_ref.to_string(),
+            })
+    }
 }
 
 /// # Panics
 ///
-/// Panics if the commitment for the table or column cannot be found.
+/// Panics if there is no commitment for the table or column. Use
+/// [`CommitmentAccessor::try_get_commitment`] to get an error instead.
 impl<C: Commitment> CommitmentAccessor<C> for QueryCommitments<C> {
     fn get_commitment(&self, column: ColumnRef) -> C {
-        let table_commitment = self.get(&column.table_ref()).unwrap();
+        self.try_get_commitment(column)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
 
-        table_commitment
+    fn try_get_commitment(&self, column: ColumnRef) -> Result<C, ProofError> {
+        self.get(&column.table_ref())
+            .ok_or_else(|| ProofError::MissingTableCommitment {
+                table: column.table_ref().to_string(),
+            })?
             .column_commitments()
             .get_commitment(&column.column_id())
-            .unwrap()
+            .ok_or_else(|| ProofError::MissingColumnCommitment {
+                table: column.table_ref().to_string(),
+                column: column.column_id().to_string(),
+            })
     }
 }
 
@@ -249,6 +271,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn we_cannot_get_commitment_of_a_column_in_a_missing_table() {
+        let table_a: OwnedTable<Curve25519Scalar> = owned_table([bigint("column_a", [1, 2])]);
+        let table_a_commitment =
+            TableCommitment::<RistrettoPoint>::from_owned_table_with_offset(&table_a, 0, &());
+        let query_commitments =
+            QueryCommitments::from_iter([("table.a".parse().unwrap(), table_a_commitment)]);
+
+        let table_b = "table.b".parse().unwrap();
+        assert!(matches!(
+            query_commitments.try_get_length(table_b),
+            Err(ProofError::MissingTableCommitment { table }) if table == "table.b"
+        ));
+        assert!(matches!(
+            query_commitments.try_get_commitment(ColumnRef::new(
+                table_b,
+                "column_a".parse().unwrap(),
+                ColumnType::BigInt,
+            )),
+            Err(ProofError::MissingTableCommitment { table }) if table == "table.b"
+        ));
+    }
+
+    #[test]
+    fn we_cannot_get_commitment_of_a_missing_column() {
+        let table_a: OwnedTable<Curve25519Scalar> = owned_table([bigint("column_a", [1, 2])]);
+        let table_a_commitment =
+            TableCommitment::<RistrettoPoint>::from_owned_table_with_offset(&table_a, 0, &());
+        let table_a = "table.a".parse().unwrap();
+        let query_commitments = QueryCommitments::from_iter([(table_a, table_a_commitment)]);
+
+        assert_eq!(query_commitments.try_get_length(table_a).unwrap(), 2);
+        assert!(matches!(
+            query_commitments.try_get_commitment(ColumnRef::new(
+                table_a,
+                "column_b".parse().unwrap(),
+                ColumnType::BigInt,
+            )),
+            Err(ProofError::MissingColumnCommitment { table, column })
+                if table == "table.a" && column == "column_b"
+        ));
+    }
+
     #[allow(clippy::similar_names)]
     #[test]
     fn we_can_get_schema_of_tables() {
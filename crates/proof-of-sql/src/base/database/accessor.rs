@@ -1,6 +1,7 @@
 use crate::base::{
     commitment::Commitment,
-    database::{Column, ColumnRef, ColumnType, TableRef},
+    database::{Column, ColumnRef, ColumnStats, ColumnType, TableRef},
+    proof::ProofError,
     scalar::Scalar,
 };
 use alloc::vec::Vec;
@@ -21,6 +22,14 @@ pub trait MetadataAccessor {
     /// If the data span has its first row starting at the ith table row,
     /// this `get_offset` should then return `i`.
     fn get_offset(&self, table_ref: TableRef) -> usize;
+
+    /// Return the data span's length in the table, or an error if the table is unknown.
+    ///
+    /// The verifier checks every table a query reads with this method before any other lookup,
+    /// so an accessor built from untrusted input should override it to fail instead of panicking.
+    fn try_get_length(&self, table_ref: TableRef) -> Result<usize, ProofError> {
+        Ok(self.get_length(table_ref))
+    }
 }
 
 /// Access commitments of database columns.
@@ -51,6 +60,14 @@ pub trait MetadataAccessor {
 pub trait CommitmentAccessor<C: Commitment>: MetadataAccessor {
     /// Return the full table column commitment
     fn get_commitment(&self, column: ColumnRef) -> C;
+
+    /// Return the full table column commitment, or an error if the column is unknown.
+    ///
+    /// The verifier looks commitments up with this method, so an accessor built from untrusted
+    /// input should override it to fail instead of panicking.
+    fn try_get_commitment(&self, column: ColumnRef) -> Result<C, ProofError> {
+        Ok(self.get_commitment(column))
+    }
 }
 
 /// Access database columns of an in-memory table span.
@@ -85,6 +102,14 @@ pub trait CommitmentAccessor<C: Commitment>: MetadataAccessor {
 pub trait DataAccessor<S: Scalar>: MetadataAccessor {
     /// Return the data span in the table (not the full-table data)
     fn get_column(&self, column: ColumnRef) -> Column<S>;
+
+    /// Return statistics about the data span of a column
+    ///
+    /// These are only hints, so implementations may override this with cheaper estimates.
+    /// By default, exact statistics are computed from [`DataAccessor::get_column`].
+    fn get_column_stats(&self, column: ColumnRef) -> ColumnStats {
+        ColumnStats::from_column(&self.get_column(column))
+    }
 }
 
 /// Access tables and their schemas in a database.
@@ -0,0 +1,36 @@
+use super::Column;
+use crate::base::{
+    commitment::{ColumnBounds, CommittableColumn},
+    scalar::Scalar,
+};
+use alloc::vec::Vec;
+
+/// Statistics about the data span of a column.
+///
+/// These are only hints for choosing how to prove a query. A proof is sound no matter what
+/// statistics were used to plan it, so accessors may return cheap estimates instead of exact values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ColumnStats {
+    /// The minimum and maximum of the column, if its type has order
+    pub bounds: ColumnBounds,
+    /// An estimate of the number of distinct values in the column
+    pub distinct_count: usize,
+}
+
+impl ColumnStats {
+    /// Compute exact statistics of a column.
+    ///
+    /// `VarChar` values are counted by their scalar hashes.
+    #[must_use]
+    pub fn from_column<S: Scalar>(column: &Column<S>) -> Self {
+        let mut scalars: Vec<S> = (0..column.len())
+            .filter_map(|index| column.scalar_at(index))
+            .collect();
+        scalars.sort_unstable();
+        scalars.dedup();
+        Self {
+            bounds: ColumnBounds::from_column(&CommittableColumn::from(column)),
+            distinct_count: scalars.len(),
+        }
+    }
+}
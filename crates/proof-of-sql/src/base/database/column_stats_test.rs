@@ -0,0 +1,45 @@
+use super::{Column, ColumnStats};
+use crate::base::{
+    commitment::{Bounds, ColumnBounds},
+    scalar::Curve25519Scalar,
+};
+
+#[test]
+fn we_can_compute_the_stats_of_an_ordered_column() {
+    let column = Column::<Curve25519Scalar>::BigInt(&[3, -1, 3, 7, -1, 3]);
+    assert_eq!(
+        ColumnStats::from_column(&column),
+        ColumnStats {
+            bounds: ColumnBounds::BigInt(Bounds::sharp(-1, 7).unwrap()),
+            distinct_count: 3,
+        }
+    );
+}
+
+#[test]
+fn we_can_compute_the_stats_of_an_unordered_column() {
+    let strings = ["a", "b", "a", "a"];
+    let scalars = strings.map(Curve25519Scalar::from);
+    let column = Column::VarChar((&strings[..], &scalars[..]));
+    assert_eq!(
+        ColumnStats::from_column(&column),
+        ColumnStats {
+            bounds: ColumnBounds::NoOrder,
+            distinct_count: 2,
+        }
+    );
+    let column = Column::<Curve25519Scalar>::Boolean(&[true, true]);
+    assert_eq!(ColumnStats::from_column(&column).distinct_count, 1);
+}
+
+#[test]
+fn we_can_compute_the_stats_of_an_empty_column() {
+    let column = Column::<Curve25519Scalar>::Int(&[]);
+    assert_eq!(
+        ColumnStats::from_column(&column),
+        ColumnStats {
+            bounds: ColumnBounds::Int(Bounds::Empty),
+            distinct_count: 0,
+        }
+    );
+}
@@ -21,6 +21,10 @@ impl<S: Scalar> OwnedTable<S> {
             Expression::Literal(lit) => self.evaluate_literal(lit),
             Expression::Binary { op, left, right } => self.evaluate_binary_expr(*op, left, right),
             Expression::Unary { op, expr } => self.evaluate_unary_expr(*op, expr),
+            Expression::InList { expr, list } => self.evaluate_in_list_expr(expr, list),
+            Expression::Case { .. } => Err(ExpressionEvaluationError::Unsupported {
+                expression: "CASE WHEN is only supported in provable expressions".to_string(),
+            }),
             _ => Err(ExpressionEvaluationError::Unsupported {
                 expression: format!("Expression {expr:?} is not supported yet"),
             }),
@@ -77,6 +81,21 @@ impl<S: Scalar> OwnedTable<S> {
         }
     }
 
+    fn evaluate_in_list_expr(
+        &self,
+        expr: &Expression,
+        list: &[Literal],
+    ) -> ExpressionEvaluationResult<OwnedColumn<S>> {
+        let column = self.evaluate(expr)?;
+        list.iter().try_fold(
+            OwnedColumn::Boolean(vec![false; self.num_rows()]),
+            |is_in_list, lit| {
+                let is_equal = column.element_wise_eq(&self.evaluate_literal(lit)?)?;
+                Ok(is_in_list.element_wise_or(&is_equal)?)
+            },
+        )
+    }
+
     fn evaluate_binary_expr(
         &self,
         op: BinaryOperator,
@@ -95,6 +114,9 @@ impl<S: Scalar> OwnedTable<S> {
             BinaryOperator::Subtract => Ok((left - right)?),
             BinaryOperator::Multiply => Ok((left * right)?),
             BinaryOperator::Division => Ok((left / right)?),
+            BinaryOperator::Modulo => Err(ExpressionEvaluationError::Unsupported {
+                expression: "Modulo is only supported in provable expressions".to_string(),
+            }),
         }
     }
 }
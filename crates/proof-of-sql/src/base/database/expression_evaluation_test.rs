@@ -140,6 +140,29 @@ fn we_can_evaluate_a_logical_expression() {
     assert_eq!(actual_column, expected_column);
 }
 
+#[test]
+fn we_can_evaluate_an_in_list_expression() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("status", [1, 2, 3, 7, 0]),
+        varchar("name", ["a", "b", "c", "d", "e"]),
+    ]);
+    let expr = in_list(
+        col("status"),
+        vec![Literal::BigInt(1), Literal::BigInt(3), Literal::BigInt(7)],
+    );
+    let actual_column = table.evaluate(&expr).unwrap();
+    let expected_column = OwnedColumn::Boolean(vec![true, false, true, true, false]);
+    assert_eq!(actual_column, expected_column);
+
+    let expr = not(in_list(
+        col("name"),
+        vec![Literal::VarChar("b".to_string())],
+    ));
+    let actual_column = table.evaluate(&expr).unwrap();
+    let expected_column = OwnedColumn::Boolean(vec![true, false, true, true, true]);
+    assert_eq!(actual_column, expected_column);
+}
+
 #[test]
 fn we_can_evaluate_an_arithmetic_expression() {
     let table: OwnedTable<Curve25519Scalar> = owned_table([
@@ -15,6 +15,11 @@ pub use column_operation::{
 mod column_operation_error;
 pub use column_operation_error::{ColumnOperationError, ColumnOperationResult};
 
+mod column_stats;
+pub use column_stats::ColumnStats;
+#[cfg(test)]
+mod column_stats_test;
+
 mod columnar_value;
 pub use columnar_value::ColumnarValue;
 
@@ -45,8 +50,7 @@ pub use owned_column_error::{OwnedColumnError, OwnedColumnResult};
 pub(crate) mod owned_column_operation;
 
 mod owned_table;
-pub use owned_table::OwnedTable;
-pub(crate) use owned_table::OwnedTableError;
+pub use owned_table::{OwnedTable, OwnedTableError};
 #[cfg(test)]
 mod owned_table_test;
 pub mod owned_table_utility;
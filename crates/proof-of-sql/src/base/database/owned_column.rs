@@ -2,7 +2,7 @@
 /// This is primarily used as an internal result that is used before
 /// converting to the final result in either Arrow format or JSON.
 /// This is the analog of an arrow Array.
-use super::{Column, ColumnType, OwnedColumnError, OwnedColumnResult};
+use super::{Column, ColumnType, LiteralValue, OwnedColumnError, OwnedColumnResult};
 use crate::base::{
     math::{
         decimal::Precision,
@@ -19,8 +19,9 @@ use proof_of_sql_parser::{
     intermediate_ast::OrderByDirection,
     posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
 };
+use serde::Serialize;
 
-#[derive(Debug, PartialEq, Clone, Eq)]
+#[derive(Debug, PartialEq, Clone, Eq, Serialize)]
 #[non_exhaustive]
 /// Supported types for [`OwnedColumn`]
 pub enum OwnedColumn<S: Scalar> {
@@ -136,6 +137,30 @@ impl<S: Scalar> OwnedColumn<S> {
         }
     }
 
+    /// Returns the entry at `index` as a typed value, or `None` if `index` is out of bounds.
+    #[must_use]
+    pub fn literal_at(&self, index: usize) -> Option<LiteralValue<S>> {
+        Some(match self {
+            OwnedColumn::Boolean(col) => LiteralValue::Boolean(*col.get(index)?),
+            OwnedColumn::TinyInt(col) => LiteralValue::TinyInt(*col.get(index)?),
+            OwnedColumn::SmallInt(col) => LiteralValue::SmallInt(*col.get(index)?),
+            OwnedColumn::Int(col) => LiteralValue::Int(*col.get(index)?),
+            OwnedColumn::BigInt(col) => LiteralValue::BigInt(*col.get(index)?),
+            OwnedColumn::VarChar(col) => {
+                let string = col.get(index)?;
+                LiteralValue::VarChar((string.clone(), string.as_str().into()))
+            }
+            OwnedColumn::Int128(col) => LiteralValue::Int128(*col.get(index)?),
+            OwnedColumn::Decimal75(precision, scale, col) => {
+                LiteralValue::Decimal75(*precision, *scale, *col.get(index)?)
+            }
+            OwnedColumn::Scalar(col) => LiteralValue::Scalar(*col.get(index)?),
+            OwnedColumn::TimestampTZ(tu, tz, col) => {
+                LiteralValue::TimeStampTZ(*tu, *tz, *col.get(index)?)
+            }
+        })
+    }
+
     /// Convert a slice of scalars to a vec of owned columns
     pub fn try_from_scalars(scalars: &[S], column_type: ColumnType) -> OwnedColumnResult<Self> {
         match column_type {
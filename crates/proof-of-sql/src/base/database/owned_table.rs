@@ -1,6 +1,8 @@
-use super::OwnedColumn;
+use super::{ColumnType, LiteralValue, OwnedColumn};
 use crate::base::{map::IndexMap, scalar::Scalar};
+use alloc::{string::String, vec::Vec};
 use proof_of_sql_parser::Identifier;
+use serde::Serialize;
 use snafu::Snafu;
 
 /// An error that occurs when working with tables.
@@ -9,13 +11,58 @@ pub enum OwnedTableError {
     /// The columns have different lengths.
     #[snafu(display("Columns have different lengths"))]
     ColumnLengthMismatch,
+    /// The table has no column with the given name.
+    #[snafu(display("Column {name} not found"))]
+    ColumnNotFound {
+        /// The name of the missing column
+        name: String,
+    },
+    /// The column does not have the requested type.
+    #[snafu(display("Column {name} has type {actual} but {expected} was requested"))]
+    ColumnTypeMismatch {
+        /// The name of the column
+        name: String,
+        /// The requested type
+        expected: ColumnType,
+        /// The type of the column
+        actual: ColumnType,
+    },
+    /// The row index is past the end of the table.
+    #[snafu(display("Row {index} is out of bounds for a table with {num_rows} rows"))]
+    RowOutOfBounds {
+        /// The requested row
+        index: usize,
+        /// The number of rows in the table
+        num_rows: usize,
+    },
 }
+
+/// Generates a typed accessor for the columns of an [`OwnedTable`].
+macro_rules! column_accessor {
+    ($name:ident, $t:ty, $expected:expr, $variant:path) => {
+        #[doc = concat!("Returns the column `name` as a slice of [`", stringify!($t), "`].")]
+        ///
+        /// # Errors
+        /// Returns an error if there is no such column or it has a different type.
+        pub fn $name(&self, name: &str) -> Result<&[$t], OwnedTableError> {
+            match self.column(name)? {
+                $variant(col) => Ok(col),
+                column => Err(OwnedTableError::ColumnTypeMismatch {
+                    name: name.into(),
+                    expected: $expected,
+                    actual: column.column_type(),
+                }),
+            }
+        }
+    };
+}
+
 /// A table of data, with schema included. This is simply a map from `Identifier` to `OwnedColumn`,
 /// where columns order matters.
 /// This is primarily used as an internal result that is used before
 /// converting to the final result in either Arrow format or JSON.
 /// This is the analog of an arrow [`RecordBatch`](arrow::record_batch::RecordBatch).
-#[derive(Debug, Clone, Eq)]
+#[derive(Debug, Clone, Eq, Serialize)]
 pub struct OwnedTable<S: Scalar> {
     table: IndexMap<Identifier, OwnedColumn<S>>,
 }
@@ -71,6 +118,48 @@ impl<S: Scalar> OwnedTable<S> {
     pub fn column_names(&self) -> impl Iterator<Item = &Identifier> {
         self.table.keys()
     }
+    /// Returns the column `name`.
+    ///
+    /// # Errors
+    /// Returns an error if there is no such column.
+    pub fn column(&self, name: &str) -> Result<&OwnedColumn<S>, OwnedTableError> {
+        name.parse::<Identifier>()
+            .ok()
+            .and_then(|identifier| self.table.get(&identifier))
+            .ok_or_else(|| OwnedTableError::ColumnNotFound { name: name.into() })
+    }
+    column_accessor!(column_bool, bool, ColumnType::Boolean, OwnedColumn::Boolean);
+    column_accessor!(column_i8, i8, ColumnType::TinyInt, OwnedColumn::TinyInt);
+    column_accessor!(column_i16, i16, ColumnType::SmallInt, OwnedColumn::SmallInt);
+    column_accessor!(column_i32, i32, ColumnType::Int, OwnedColumn::Int);
+    column_accessor!(column_i64, i64, ColumnType::BigInt, OwnedColumn::BigInt);
+    column_accessor!(column_i128, i128, ColumnType::Int128, OwnedColumn::Int128);
+    column_accessor!(
+        column_varchar,
+        String,
+        ColumnType::VarChar,
+        OwnedColumn::VarChar
+    );
+    column_accessor!(column_scalar, S, ColumnType::Scalar, OwnedColumn::Scalar);
+    /// Returns the entries of row `index`, in column order.
+    ///
+    /// # Errors
+    /// Returns an error if `index` is out of bounds.
+    pub fn row(&self, index: usize) -> Result<Vec<LiteralValue<S>>, OwnedTableError> {
+        self.table
+            .values()
+            .map(|column| column.literal_at(index))
+            .collect::<Option<_>>()
+            .filter(|_| index < self.num_rows())
+            .ok_or(OwnedTableError::RowOutOfBounds {
+                index,
+                num_rows: self.num_rows(),
+            })
+    }
+    /// Returns an iterator over the rows of this table, with the entries of each row in column order.
+    pub fn rows(&self) -> impl Iterator<Item = Vec<LiteralValue<S>>> + '_ {
+        (0..self.num_rows()).map_while(|index| self.row(index).ok())
+    }
 }
 
 // Note: we modify the default PartialEq for IndexMap to also check for column ordering.
@@ -1,7 +1,11 @@
 use crate::{
     base::{
-        database::{owned_table_utility::*, OwnedColumn, OwnedTable, OwnedTableError},
+        database::{
+            owned_table_utility::*, ColumnType, LiteralValue, OwnedColumn, OwnedTable,
+            OwnedTableError,
+        },
         map::IndexMap,
+        math::decimal::Precision,
         scalar::Curve25519Scalar,
     },
     proof_primitive::dory::DoryScalar,
@@ -16,6 +20,7 @@ fn we_can_create_an_owned_table_with_no_columns() {
     let table = OwnedTable::<Curve25519Scalar>::try_new(IndexMap::default()).unwrap();
     assert_eq!(table.num_columns(), 0);
 }
+
 #[test]
 fn we_can_create_an_empty_owned_table() {
     let owned_table = owned_table::<DoryScalar>([
@@ -48,6 +53,7 @@ fn we_can_create_an_empty_owned_table() {
     );
     assert_eq!(owned_table.into_inner(), table);
 }
+
 #[test]
 fn we_can_create_an_owned_table_with_data() {
     let owned_table = owned_table([
@@ -119,6 +125,7 @@ fn we_can_create_an_owned_table_with_data() {
     );
     assert_eq!(owned_table.into_inner(), table);
 }
+
 #[test]
 fn we_get_inequality_between_tables_with_differing_column_order() {
     let owned_table_a: OwnedTable<Curve25519Scalar> = owned_table([
@@ -147,6 +154,7 @@ fn we_get_inequality_between_tables_with_differing_column_order() {
     ]);
     assert_ne!(owned_table_a, owned_table_b);
 }
+
 #[test]
 fn we_get_inequality_between_tables_with_differing_data() {
     let owned_table_a: OwnedTable<DoryScalar> = owned_table([
@@ -175,6 +183,7 @@ fn we_get_inequality_between_tables_with_differing_data() {
     ]);
     assert_ne!(owned_table_a, owned_table_b);
 }
+
 #[test]
 fn we_cannot_create_an_owned_table_with_differing_column_lengths() {
     assert!(matches!(
@@ -185,3 +194,105 @@ fn we_cannot_create_an_owned_table_with_differing_column_lengths() {
         Err(OwnedTableError::ColumnLengthMismatch)
     ));
 }
+
+#[test]
+fn we_can_access_the_columns_of_an_owned_table_by_type() {
+    let table = owned_table::<Curve25519Scalar>([
+        bigint("a", [1, 2]),
+        varchar("b", ["x", "y"]),
+        boolean("c", [true, false]),
+        timestamptz("d", PoSQLTimeUnit::Second, PoSQLTimeZone::Utc, [3, 4]),
+        scalar("e", [5, 6]),
+    ]);
+    assert_eq!(table.column_i64("a").unwrap(), &[1, 2]);
+    assert_eq!(table.column_varchar("b").unwrap(), &["x", "y"]);
+    assert_eq!(table.column_bool("c").unwrap(), &[true, false]);
+    assert_eq!(
+        table.column_scalar("e").unwrap(),
+        &[Curve25519Scalar::from(5), Curve25519Scalar::from(6)]
+    );
+    assert_eq!(table.column("A").unwrap(), &OwnedColumn::BigInt(vec![1, 2]));
+}
+
+#[test]
+fn we_cannot_access_a_missing_column_or_a_column_of_the_wrong_type() {
+    let table = owned_table::<Curve25519Scalar>([bigint("a", [1, 2])]);
+    assert_eq!(
+        table.column_i64("b"),
+        Err(OwnedTableError::ColumnNotFound { name: "b".into() })
+    );
+    assert_eq!(
+        table.column_i64("not an identifier"),
+        Err(OwnedTableError::ColumnNotFound {
+            name: "not an identifier".into()
+        })
+    );
+    assert_eq!(
+        table.column_i32("a"),
+        Err(OwnedTableError::ColumnTypeMismatch {
+            name: "a".into(),
+            expected: ColumnType::Int,
+            actual: ColumnType::BigInt,
+        })
+    );
+}
+
+#[test]
+fn we_cannot_access_timestamp_or_decimal_columns_as_their_underlying_type() {
+    let table = owned_table::<Curve25519Scalar>([
+        timestamptz("a", PoSQLTimeUnit::Second, PoSQLTimeZone::Utc, [3, 4]),
+        decimal75("b", 5, 2, [300, 400]),
+    ]);
+    assert_eq!(
+        table.column_i64("a"),
+        Err(OwnedTableError::ColumnTypeMismatch {
+            name: "a".into(),
+            expected: ColumnType::BigInt,
+            actual: ColumnType::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::Utc),
+        })
+    );
+    assert_eq!(
+        table.column_scalar("b"),
+        Err(OwnedTableError::ColumnTypeMismatch {
+            name: "b".into(),
+            expected: ColumnType::Scalar,
+            actual: ColumnType::Decimal75(Precision::new(5).unwrap(), 2),
+        })
+    );
+}
+
+#[test]
+fn we_can_iterate_over_the_rows_of_an_owned_table() {
+    let table = owned_table::<Curve25519Scalar>([
+        bigint("a", [1, 2]),
+        varchar("b", ["x", "y"]),
+        decimal75("c", 5, 2, [300, 400]),
+    ]);
+    let rows: Vec<_> = table.rows().collect();
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                LiteralValue::BigInt(1),
+                LiteralValue::VarChar(("x".into(), "x".into())),
+                LiteralValue::Decimal75(Precision::new(5).unwrap(), 2, 300.into()),
+            ],
+            vec![
+                LiteralValue::BigInt(2),
+                LiteralValue::VarChar(("y".into(), "y".into())),
+                LiteralValue::Decimal75(Precision::new(5).unwrap(), 2, 400.into()),
+            ],
+        ]
+    );
+    assert_eq!(table.row(1).unwrap(), rows[1]);
+    assert_eq!(
+        table.row(2),
+        Err(OwnedTableError::RowOutOfBounds {
+            index: 2,
+            num_rows: 2
+        })
+    );
+    let empty = OwnedTable::<Curve25519Scalar>::try_new(IndexMap::default()).unwrap();
+    assert_eq!(empty.rows().count(), 0);
+    assert!(empty.row(0).is_err());
+}
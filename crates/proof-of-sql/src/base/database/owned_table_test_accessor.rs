@@ -195,4 +195,16 @@ impl<'a, CP: CommitmentEvaluationProof> OwnedTableTestAccessor<'a, CP> {
         res.add_table(table_ref, owned_table, offset);
         res
     }
+
+    /// Create a new test accessor containing each of the provided tables at its offset.
+    pub fn new_from_tables(
+        tables: impl IntoIterator<Item = (TableRef, OwnedTable<CP::Scalar>, usize)>,
+        setup: CP::ProverPublicSetup<'a>,
+    ) -> Self {
+        let mut res = Self::new_empty_with_setup(setup);
+        for (table_ref, owned_table, offset) in tables {
+            res.add_table(table_ref, owned_table, offset);
+        }
+        res
+    }
 }
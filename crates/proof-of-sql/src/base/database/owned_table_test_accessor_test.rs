@@ -267,3 +267,33 @@ fn we_can_correctly_update_offsets() {
     assert_eq!(accessor1.get_offset(table_ref), offset);
     assert_eq!(accessor2.get_offset(table_ref), offset);
 }
+
+#[test]
+fn we_can_create_an_accessor_from_several_tables() {
+    let table_ref_1 = "sxt.test".parse().unwrap();
+    let table_ref_2 = "other.test".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_tables(
+        [
+            (table_ref_1, owned_table([bigint("a", [1, 2, 3])]), 0),
+            (
+                table_ref_2,
+                owned_table([varchar("b", ["x", "y"]), boolean("c", [true, false])]),
+                4,
+            ),
+        ],
+        (),
+    );
+
+    assert_eq!(accessor.get_length(table_ref_1), 3);
+    assert_eq!(accessor.get_offset(table_ref_1), 0);
+    assert_eq!(accessor.get_length(table_ref_2), 2);
+    assert_eq!(accessor.get_offset(table_ref_2), 4);
+    assert_eq!(accessor.get_column_names(table_ref_2), vec!["b", "c"]);
+
+    let column = ColumnRef::new(table_ref_2, "c".parse().unwrap(), ColumnType::Boolean);
+    assert_eq!(
+        accessor.get_commitment(column),
+        RistrettoPoint::compute_commitments(&[CommittableColumn::from(&[true, false][..])], 4, &())
+            [0]
+    );
+}
@@ -11,7 +11,7 @@ pub(crate) mod encode;
 pub mod math;
 /// TODO: add docs
 pub(crate) mod polynomial;
-pub(crate) mod proof;
+pub mod proof;
 pub(crate) mod ref_into;
 /// This module contains the `Scalar` trait as well as the main, generic, implementations of it.
 pub mod scalar;
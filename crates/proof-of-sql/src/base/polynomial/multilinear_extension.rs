@@ -17,8 +17,10 @@ pub trait MultilinearExtension<S: Scalar> {
     /// convert the MLE to a form that can be used in sumcheck
     fn to_sumcheck_term(&self, num_vars: usize) -> Rc<Vec<S>>;
 
-    /// pointer to identify the slice forming the MLE
-    fn id(&self) -> *const c_void;
+    /// pointer and length to identify the slice forming the MLE
+    ///
+    /// The length is needed since an empty slice may share its pointer with another allocation.
+    fn id(&self) -> (*const c_void, usize);
 
     #[cfg(test)]
     /// Given an evaluation point, compute the evaluation of the multilinear
@@ -56,8 +58,8 @@ where
         Rc::new(scalars)
     }
 
-    fn id(&self) -> *const c_void {
-        self.as_ptr().cast::<c_void>()
+    fn id(&self) -> (*const c_void, usize) {
+        (self.as_ptr().cast::<c_void>(), self.len())
     }
 }
 
@@ -76,7 +78,7 @@ macro_rules! slice_like_mle_impl {
             (&self[..]).to_sumcheck_term(num_vars)
         }
 
-        fn id(&self) -> *const c_void {
+        fn id(&self) -> (*const c_void, usize) {
             (&self[..]).id()
         }
     };
@@ -139,7 +141,7 @@ impl<S: Scalar> MultilinearExtension<S> for &Column<'_, S> {
         }
     }
 
-    fn id(&self) -> *const c_void {
+    fn id(&self) -> (*const c_void, usize) {
         match self {
             Column::Boolean(c) => MultilinearExtension::<S>::id(c),
             Column::Scalar(c) | Column::VarChar((_, c)) | Column::Decimal75(_, _, c) => {
@@ -167,7 +169,7 @@ impl<S: Scalar> MultilinearExtension<S> for Column<'_, S> {
         (&self).to_sumcheck_term(num_vars)
     }
 
-    fn id(&self) -> *const c_void {
+    fn id(&self) -> (*const c_void, usize) {
         (&self).id()
     }
 }
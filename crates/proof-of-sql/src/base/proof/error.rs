@@ -1,3 +1,4 @@
+use alloc::string::String;
 use snafu::Snafu;
 
 #[derive(Snafu, Debug)]
@@ -5,5 +6,64 @@ use snafu::Snafu;
 pub enum ProofError {
     #[snafu(display("Verification error: {error}"))]
     /// This error occurs when a proof failed to verify.
-    VerificationError { error: &'static str },
+    VerificationError {
+        /// The check that failed
+        error: &'static str,
+    },
+    #[snafu(display("Expected {expected} result columns but the result has {actual}"))]
+    /// This error occurs when the number of columns in the result does not match the query.
+    ColumnCountMismatch {
+        /// The number of columns the query produces
+        expected: usize,
+        /// The number of columns in the result
+        actual: usize,
+    },
+    #[snafu(display("Expected {expected} intermediate commitments but the proof has {actual}"))]
+    /// This error occurs when the number of intermediate commitments in a proof is wrong.
+    CommitmentCountMismatch {
+        /// The number of intermediate commitments the query requires
+        expected: usize,
+        /// The number of intermediate commitments in the proof
+        actual: usize,
+    },
+    #[snafu(display("Expected {expected} MLE evaluations but the proof has {actual}"))]
+    /// This error occurs when the number of MLE evaluations in a proof is wrong.
+    MleEvaluationCountMismatch {
+        /// The number of MLE evaluations the query requires
+        expected: usize,
+        /// The number of MLE evaluations in the proof
+        actual: usize,
+    },
+    #[snafu(display("The result has {actual} rows but at most {max} are allowed"))]
+    /// This error occurs when the result claims more rows than the query can produce.
+    ResultTooLarge {
+        /// The largest number of rows the query can produce
+        max: usize,
+        /// The number of rows in the result
+        actual: usize,
+    },
+    #[snafu(display(
+        "The query requires {actual} sumcheck variables but at most {max} are allowed"
+    ))]
+    /// This error occurs when verifying a proof would exceed the verifier's work budget.
+    ExceedsBudget {
+        /// The largest number of sumcheck variables the verifier accepts
+        max: usize,
+        /// The number of sumcheck variables the query requires
+        actual: usize,
+    },
+    #[snafu(display("No commitment for table {table}"))]
+    /// This error occurs when the verifier has no commitment for a table the query reads.
+    MissingTableCommitment {
+        /// The table without a commitment
+        table: String,
+    },
+    #[snafu(display("No commitment for column {column} of table {table}"))]
+    /// This error occurs when the verifier has no commitment for a column the query reads.
+    MissingColumnCommitment {
+        /// The table of the column
+        table: String,
+        /// The column without a commitment
+        column: String,
+    },
 }
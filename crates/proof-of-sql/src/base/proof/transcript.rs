@@ -6,7 +6,9 @@ use zerocopy::{AsBytes, FromBytes};
 ///
 /// This trait contains several method for adding prover messages and computing verifier challenges.
 ///
-/// Implementation note: this is intended to be implemented via [`super::transcript_core::TranscriptCore`] rather than directly.
+/// Implementation note: the transcripts in this crate implement this via `TranscriptCore`, which hashes bytes.
+/// A transcript that absorbs scalars natively, such as a Poseidon sponge, can implement it directly and be
+/// passed to [`QueryProof::new_with_options`](crate::sql::proof::QueryProof::new_with_options).
 #[allow(dead_code)]
 pub trait Transcript {
     /// Creates a new transcript
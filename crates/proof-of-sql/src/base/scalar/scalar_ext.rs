@@ -1,5 +1,6 @@
 use super::Scalar;
 use core::cmp::Ordering;
+use subtle::{Choice, ConstantTimeEq};
 
 /// Extention trait for blanket implementations for `Scalar` types.
 /// This trait is primarily to avoid cluttering the core `Scalar` implementation with default implemenentations
@@ -17,6 +18,23 @@ pub trait ScalarExt: Scalar {
             _ => Ordering::Greater,
         }
     }
+    /// Check two `Scalar`s for equality in constant time by comparing their limbs.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let lhs: [u64; 4] = (*self).into();
+        let rhs: [u64; 4] = (*other).into();
+        lhs.ct_eq(&rhs)
+    }
+    /// Check two slices of `Scalar`s for equality in constant time.
+    ///
+    /// Only the lengths are compared in variable time.
+    fn ct_eq_slice(lhs: &[Self], rhs: &[Self]) -> Choice {
+        if lhs.len() != rhs.len() {
+            return Choice::from(0);
+        }
+        lhs.iter()
+            .zip(rhs)
+            .fold(Choice::from(1), |acc, (l, r)| acc & l.ct_eq(r))
+    }
 }
 impl<S: Scalar> ScalarExt for S {}
 
@@ -38,6 +56,25 @@ mod tests {
         assert_eq!(two * max + one, zero);
     }
     #[test]
+    fn we_can_compare_scalars_in_constant_time() {
+        let one = Curve25519Scalar::ONE;
+        let two = Curve25519Scalar::TWO;
+        assert!(bool::from(ScalarExt::ct_eq(&two, &(one + one))));
+        assert!(!bool::from(ScalarExt::ct_eq(&one, &two)));
+        assert!(bool::from(Curve25519Scalar::ct_eq_slice(
+            &[one, two],
+            &[one, two]
+        )));
+        assert!(!bool::from(Curve25519Scalar::ct_eq_slice(
+            &[one, two],
+            &[one, one]
+        )));
+        assert!(!bool::from(Curve25519Scalar::ct_eq_slice(
+            &[one, two],
+            &[one]
+        )));
+    }
+    #[test]
     fn we_can_compute_powers_of_10() {
         for i in 0..=u128::MAX.ilog10() {
             assert_eq!(
@@ -8,8 +8,12 @@ extern crate alloc;
 pub mod base;
 pub mod proof_primitive;
 pub mod sql;
+#[cfg(any(test, feature = "test"))]
+pub mod testing;
 /// Utilities for working with the library
 pub mod utils;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 #[cfg(test)]
 mod tests;
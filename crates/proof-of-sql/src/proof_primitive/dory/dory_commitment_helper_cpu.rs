@@ -1,8 +1,10 @@
 use super::{pairings, DoryCommitment, DoryProverPublicSetup, DoryScalar, G1Projective};
-use crate::base::commitment::CommittableColumn;
+use crate::base::{commitment::CommittableColumn, if_rayon};
 use alloc::vec::Vec;
 use ark_ec::VariableBaseMSM;
 use core::iter::once;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 #[tracing::instrument(name = "compute_dory_commitment_impl (cpu)", level = "debug", skip_all)]
 /// # Panics
@@ -84,8 +86,7 @@ pub(super) fn compute_dory_commitments(
     offset: usize,
     setup: &DoryProverPublicSetup,
 ) -> Vec<DoryCommitment> {
-    committable_columns
-        .iter()
+    if_rayon!(committable_columns.par_iter(), committable_columns.iter())
         .map(|column| compute_dory_commitment(column, offset, setup))
         .collect()
 }
@@ -36,6 +36,10 @@ fn convert_scalar_to_array(
 }
 
 /// Compute the commitments to the rows of the matrix M that is derived from `a`.
+///
+/// # Panics
+///
+/// Panics if the size of the scalar type does not fit in a `u32`.
 #[tracing::instrument(level = "debug", skip_all)]
 #[cfg(feature = "blitzar")]
 pub(super) fn compute_T_vec_prime(
@@ -304,6 +304,11 @@ fn compute_cumulative_bit_sum_table(
 /// * `committable_columns` - A reference to the committable columns.
 /// * `offset` - The offset to the data.
 /// * `num_matrix_commitment_columns` - The number of generators used for msm.
+///
+/// # Panics
+///
+/// Panics if `BYTE_SIZE` does not fit in a `u32`.
+#[allow(clippy::too_many_lines)]
 #[tracing::instrument(
     name = "pack_scalars::bit_table_and_scalars_for_packed_msm",
     level = "debug",
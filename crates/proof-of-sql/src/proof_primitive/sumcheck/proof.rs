@@ -5,7 +5,7 @@ use crate::{
             CompositePolynomialInfo,
         },
         proof::{ProofError, Transcript},
-        scalar::Scalar,
+        scalar::{Scalar, ScalarExt},
     },
     proof_primitive::sumcheck::{prove_round, ProverState},
 };
@@ -27,6 +27,23 @@ pub struct Subclaim<S: Scalar> {
 }
 
 impl<S: Scalar> SumcheckProof<S> {
+    /// Create a proof from its round coefficients.
+    #[cfg(feature = "std")]
+    pub(crate) fn from_coefficients(coefficients: Vec<S>) -> Self {
+        Self { coefficients }
+    }
+
+    /// The number of round coefficients in the proof.
+    pub(crate) fn num_coefficients(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// Mutable access to the round coefficients, so that tests can corrupt the proof.
+    #[cfg(any(test, feature = "test"))]
+    pub(crate) fn coefficients_mut(&mut self) -> &mut Vec<S> {
+        &mut self.coefficients
+    }
+
     #[tracing::instrument(name = "SumcheckProof::create", level = "debug", skip_all)]
     pub fn create(
         transcript: &mut impl Transcript,
@@ -100,7 +117,7 @@ impl<S: Scalar> SumcheckProof<S> {
                 round_evaluation += self.coefficients[coefficient_index];
                 actual_sum += self.coefficients[coefficient_index];
             }
-            if actual_sum != expected_evaluation {
+            if !bool::from(actual_sum.ct_eq(&expected_evaluation)) {
                 return Err(ProofError::VerificationError {
                     error: "round evaluation does not match claimed sum",
                 });
@@ -246,3 +246,53 @@ fn we_can_verify_many_random_test_cases() {
         );
     }
 }
+
+#[cfg(feature = "rayon")]
+#[test]
+fn we_get_identical_proofs_with_any_number_of_threads() {
+    let create_proofs = |num_threads| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap()
+            .install(|| {
+                sumcheck_test_cases::<TestScalar>(&mut ark_std::test_rng())
+                    .map(|test_case| {
+                        let mut transcript = Transcript::new(b"sumchecktest");
+                        let mut evaluation_point = vec![MontScalar::default(); test_case.num_vars];
+                        let proof = SumcheckProof::create(
+                            &mut transcript,
+                            &mut evaluation_point,
+                            &test_case.polynomial,
+                        );
+                        let info = CompositePolynomialInfo {
+                            max_multiplicands: test_case.max_multiplicands,
+                            num_variables: test_case.num_vars,
+                        };
+                        (proof, evaluation_point, info, test_case.sum)
+                    })
+                    .collect::<Vec<_>>()
+            })
+    };
+    let single_threaded = create_proofs(1);
+    let multi_threaded = create_proofs(4);
+    assert_eq!(single_threaded.len(), multi_threaded.len());
+
+    for ((proof, evaluation_point, info, sum), (other_proof, other_evaluation_point, _, _)) in
+        single_threaded.iter().zip(&multi_threaded)
+    {
+        assert_eq!(
+            postcard::to_allocvec(proof).unwrap(),
+            postcard::to_allocvec(other_proof).unwrap(),
+            "the proof should not depend on the number of threads"
+        );
+        assert_eq!(evaluation_point, other_evaluation_point);
+        for proof in [proof, other_proof] {
+            let mut transcript = Transcript::new(b"sumchecktest");
+            let subclaim = proof
+                .verify_without_evaluation(&mut transcript, *info, sum)
+                .expect("verification should succeed with the correct setup");
+            assert_eq!(&subclaim.evaluation_point, evaluation_point);
+        }
+    }
+}
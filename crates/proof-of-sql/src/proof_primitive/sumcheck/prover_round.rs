@@ -11,6 +11,11 @@ use alloc::{vec, vec::Vec};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
+/// Computes the prover message for the current round, fixing the previous round's variable to `r_maybe`.
+///
+/// # Panics
+///
+/// Panics if a verifier message is supplied in the first round or omitted in any later round.
 #[tracing::instrument(level = "debug", skip_all)]
 pub fn prove_round<S: Scalar>(prover_state: &mut ProverState<S>, r_maybe: &Option<S>) -> Vec<S> {
     if let Some(r) = r_maybe {
@@ -98,6 +103,7 @@ pub fn prove_round<S: Scalar>(prover_state: &mut ProverState<S>, r_maybe: &Optio
             products_iter.fold(vec![S::zero(); degree + 1], vec_elementwise_add)
         )
     });
+    // Field addition is exact, so the proof is the same regardless of how rayon splits the sums.
     if_rayon!(
         sums_iter.reduce(|| vec![S::zero(); degree + 1], vec_elementwise_add),
         sums_iter.fold(vec![S::zero(); degree + 1], vec_elementwise_add)
@@ -0,0 +1,61 @@
+use crate::base::{database::TableRef, map::IndexMap};
+use alloc::boxed::Box;
+use proof_of_sql_parser::{
+    intermediate_ast::{BinaryOperator, Expression},
+    Identifier,
+};
+
+/// A row-level access policy, restricting queries to the rows a caller may see.
+///
+/// For each table it covers, the policy names a boolean column of the table marking the permitted
+/// rows. A query planned with [`QueryExpr::try_new_with_access_policy`](super::QueryExpr::try_new_with_access_policy)
+/// only reads rows where that column is true, whatever the query itself selects. Since the column
+/// is committed like any other, a verifier that plans the query with the same policy confirms that
+/// the proof only attests to the permitted rows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessPolicy {
+    permitted_rows: IndexMap<TableRef, Identifier>,
+}
+
+impl AccessPolicy {
+    /// Create a policy that permits every row of every table.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only permit the rows of `table` where the boolean column `column` is true.
+    ///
+    /// This replaces any column previously set for `table`.
+    #[must_use]
+    pub fn with_permitted_rows(mut self, table: TableRef, column: Identifier) -> Self {
+        self.permitted_rows.insert(table, column);
+        self
+    }
+
+    /// The column marking the permitted rows of `table`, if the policy covers it.
+    #[must_use]
+    pub fn permitted_rows(&self, table: &TableRef) -> Option<Identifier> {
+        self.permitted_rows.get(table).copied()
+    }
+
+    /// Add the condition that a row of `table` is permitted to `where_expr`.
+    pub(crate) fn restrict(
+        &self,
+        table: &TableRef,
+        where_expr: Option<Box<Expression>>,
+    ) -> Option<Box<Expression>> {
+        let Some(column) = self.permitted_rows(table) else {
+            return where_expr;
+        };
+        let permitted = Box::new(Expression::Column(column));
+        Some(match where_expr {
+            Some(where_expr) => Box::new(Expression::Binary {
+                op: BinaryOperator::And,
+                left: where_expr,
+                right: permitted,
+            }),
+            None => permitted,
+        })
+    }
+}
@@ -2,17 +2,19 @@ use super::ConversionError;
 use crate::{
     base::{
         commitment::Commitment,
-        database::{ColumnRef, LiteralValue},
+        database::{ColumnRef, ColumnType, LiteralValue},
         map::IndexMap,
         math::{
             decimal::{try_convert_intermediate_decimal_to_scalar, DecimalError, Precision},
             BigDecimalExt,
         },
+        scalar::Scalar,
     },
     sql::{
         parse::{
             dyn_proof_expr_builder::DecimalError::{InvalidPrecision, InvalidScale},
             ConversionError::DecimalConversionError,
+            ConversionResult,
         },
         proof_exprs::{ColumnExpr, DynProofExpr, ProofExpr},
     },
@@ -67,6 +69,12 @@ impl DynProofExprBuilder<'_> {
             Expression::Literal(lit) => self.visit_literal(lit),
             Expression::Binary { op, left, right } => self.visit_binary_expr(*op, left, right),
             Expression::Unary { op, expr } => self.visit_unary_expr(*op, expr),
+            Expression::InList { expr, list } => self.visit_in_list_expr(expr, list),
+            Expression::Case {
+                predicate,
+                then,
+                otherwise,
+            } => self.visit_case_expr(predicate, then, otherwise),
             Expression::Aggregation { op, expr } => self.visit_aggregate_expr(*op, expr),
             _ => Err(ConversionError::Unprovable {
                 error: format!("Expression {expr:?} is not supported yet"),
@@ -87,15 +95,47 @@ impl DynProofExprBuilder<'_> {
         )))
     }
 
-    #[allow(clippy::unused_self)]
     fn visit_literal<C: Commitment>(
         &self,
         lit: &Literal,
     ) -> Result<DynProofExpr<C>, ConversionError> {
+        Ok(DynProofExpr::new_literal(self.visit_literal_value(lit)?))
+    }
+
+    fn visit_in_list_expr<C: Commitment>(
+        &self,
+        expr: &Expression,
+        list: &[Literal],
+    ) -> Result<DynProofExpr<C>, ConversionError> {
+        let expr = self.visit_expr(expr)?;
+        let list = list
+            .iter()
+            .map(|lit| self.visit_literal_value(lit))
+            .collect::<Result<_, _>>()?;
+        DynProofExpr::try_new_in_list(expr, list)
+    }
+
+    fn visit_case_expr<C: Commitment>(
+        &self,
+        predicate: &Expression,
+        then: &Expression,
+        otherwise: &Expression,
+    ) -> Result<DynProofExpr<C>, ConversionError> {
+        let predicate = self.visit_expr(predicate);
+        let then = self.visit_expr(then);
+        let otherwise = self.visit_expr(otherwise);
+        DynProofExpr::try_new_case(predicate?, then?, otherwise?)
+    }
+
+    #[allow(clippy::unused_self)]
+    fn visit_literal_value<S: Scalar>(
+        &self,
+        lit: &Literal,
+    ) -> Result<LiteralValue<S>, ConversionError> {
         match lit {
-            Literal::Boolean(b) => Ok(DynProofExpr::new_literal(LiteralValue::Boolean(*b))),
-            Literal::BigInt(i) => Ok(DynProofExpr::new_literal(LiteralValue::BigInt(*i))),
-            Literal::Int128(i) => Ok(DynProofExpr::new_literal(LiteralValue::Int128(*i))),
+            Literal::Boolean(b) => Ok(LiteralValue::Boolean(*b)),
+            Literal::BigInt(i) => Ok(LiteralValue::BigInt(*i)),
+            Literal::Int128(i) => Ok(LiteralValue::Int128(*i)),
             Literal::Decimal(d) => {
                 let raw_scale = d.scale();
                 let scale = raw_scale.try_into().map_err(|_| InvalidScale {
@@ -107,16 +147,13 @@ impl DynProofExprBuilder<'_> {
                             error: d.precision().to_string(),
                         },
                     })?;
-                Ok(DynProofExpr::new_literal(LiteralValue::Decimal75(
+                Ok(LiteralValue::Decimal75(
                     precision,
                     scale,
                     try_convert_intermediate_decimal_to_scalar(d, precision, scale)?,
-                )))
+                ))
             }
-            Literal::VarChar(s) => Ok(DynProofExpr::new_literal(LiteralValue::VarChar((
-                s.clone(),
-                s.into(),
-            )))),
+            Literal::VarChar(s) => Ok(LiteralValue::VarChar((s.clone(), s.into()))),
             Literal::Timestamp(its) => {
                 let timestamp = match its.timeunit() {
                     PoSQLTimeUnit::Nanosecond => {
@@ -132,11 +169,11 @@ impl DynProofExprBuilder<'_> {
                     PoSQLTimeUnit::Second => its.timestamp().timestamp(),
                 };
 
-                Ok(DynProofExpr::new_literal(LiteralValue::TimeStampTZ(
+                Ok(LiteralValue::TimeStampTZ(
                     its.timeunit(),
                     its.timezone(),
                     timestamp,
-                )))
+                ))
             }
         }
     }
@@ -148,7 +185,7 @@ impl DynProofExprBuilder<'_> {
     ) -> Result<DynProofExpr<C>, ConversionError> {
         let expr = self.visit_expr(expr);
         match op {
-            UnaryOperator::Not => DynProofExpr::try_new_not(expr?),
+            UnaryOperator::Not => simplified_not(expr?),
         }
     }
 
@@ -162,12 +199,12 @@ impl DynProofExprBuilder<'_> {
             BinaryOperator::And => {
                 let left = self.visit_expr(left);
                 let right = self.visit_expr(right);
-                DynProofExpr::try_new_and(left?, right?)
+                simplified_and(left?, right?)
             }
             BinaryOperator::Or => {
                 let left = self.visit_expr(left);
                 let right = self.visit_expr(right);
-                DynProofExpr::try_new_or(left?, right?)
+                simplified_or(left?, right?)
             }
             BinaryOperator::Equal => {
                 let left = self.visit_expr(left);
@@ -199,6 +236,17 @@ impl DynProofExprBuilder<'_> {
                 let right = self.visit_expr(right);
                 DynProofExpr::try_new_multiply(left?, right?)
             }
+            BinaryOperator::Modulo => {
+                let left = self.visit_expr(left);
+                match right {
+                    Expression::Literal(Literal::BigInt(modulus)) => {
+                        DynProofExpr::try_new_mod(left?, *modulus)
+                    }
+                    _ => Err(ConversionError::InvalidExpression {
+                        expression: "the modulus of % must be an integer literal".to_string(),
+                    }),
+                }
+            }
             BinaryOperator::Division => Err(ConversionError::Unprovable {
                 error: format!("Binary operator {op:?} is not supported at this location"),
             }),
@@ -220,6 +268,11 @@ impl DynProofExprBuilder<'_> {
             (AggregationOperator::Count, _) | (AggregationOperator::Sum, true) => {
                 Ok(DynProofExpr::new_aggregate(op, expr))
             }
+            (AggregationOperator::Max | AggregationOperator::Min, _)
+                if expr.data_type().is_integer() =>
+            {
+                Ok(DynProofExpr::new_aggregate(op, expr))
+            }
             (AggregationOperator::Sum, false) => Err(ConversionError::InvalidExpression {
                 expression: format!(
                     "Aggregation operator {op:?} doesn't work with non-numeric types"
@@ -231,3 +284,55 @@ impl DynProofExprBuilder<'_> {
         }
     }
 }
+
+fn boolean_literal<C: Commitment>(value: bool) -> DynProofExpr<C> {
+    DynProofExpr::new_literal(LiteralValue::Boolean(value))
+}
+
+/// Builds `NOT expr`, folding a boolean literal operand so that no redundant node has to be proven.
+fn simplified_not<C: Commitment>(expr: DynProofExpr<C>) -> ConversionResult<DynProofExpr<C>> {
+    for value in [true, false] {
+        if expr == boolean_literal(value) {
+            return Ok(boolean_literal(!value));
+        }
+    }
+    DynProofExpr::try_new_not(expr)
+}
+
+/// Builds `lhs AND rhs`, folding a boolean literal operand so that no redundant node has to be proven.
+fn simplified_and<C: Commitment>(
+    lhs: DynProofExpr<C>,
+    rhs: DynProofExpr<C>,
+) -> ConversionResult<DynProofExpr<C>> {
+    if lhs.data_type() == ColumnType::Boolean && rhs.data_type() == ColumnType::Boolean {
+        if lhs == boolean_literal(true) {
+            return Ok(rhs);
+        }
+        if rhs == boolean_literal(true) || lhs == boolean_literal(false) {
+            return Ok(lhs);
+        }
+        if rhs == boolean_literal(false) {
+            return Ok(rhs);
+        }
+    }
+    DynProofExpr::try_new_and(lhs, rhs)
+}
+
+/// Builds `lhs OR rhs`, folding a boolean literal operand so that no redundant node has to be proven.
+fn simplified_or<C: Commitment>(
+    lhs: DynProofExpr<C>,
+    rhs: DynProofExpr<C>,
+) -> ConversionResult<DynProofExpr<C>> {
+    if lhs.data_type() == ColumnType::Boolean && rhs.data_type() == ColumnType::Boolean {
+        if lhs == boolean_literal(false) {
+            return Ok(rhs);
+        }
+        if rhs == boolean_literal(false) || lhs == boolean_literal(true) {
+            return Ok(lhs);
+        }
+        if rhs == boolean_literal(true) {
+            return Ok(rhs);
+        }
+    }
+    DynProofExpr::try_new_or(lhs, rhs)
+}
@@ -13,6 +13,9 @@ mod query_expr_tests;
 mod query_expr;
 pub use query_expr::QueryExpr;
 
+mod access_policy;
+pub use access_policy::AccessPolicy;
+
 mod filter_exec_builder;
 pub(crate) use filter_exec_builder::FilterExecBuilder;
 
@@ -21,7 +24,9 @@ pub(crate) mod query_context;
 pub(crate) use query_context::QueryContext;
 
 mod query_context_builder;
-pub(crate) use query_context_builder::{type_check_binary_operation, QueryContextBuilder};
+pub(crate) use query_context_builder::{
+    try_case_column_type, type_check_binary_operation, QueryContextBuilder,
+};
 
 mod dyn_proof_expr_builder;
 pub(crate) use dyn_proof_expr_builder::DynProofExprBuilder;
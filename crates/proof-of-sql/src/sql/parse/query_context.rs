@@ -6,6 +6,7 @@ use crate::{
     },
     sql::{
         parse::{ConversionError, ConversionResult, DynProofExprBuilder, WhereExprBuilder},
+        postprocessing::GroupByPostprocessing,
         proof_exprs::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, TableExpr},
         proof_plans::GroupByExec,
     },
@@ -231,76 +232,33 @@ impl<C: Commitment> TryFrom<&QueryContext> for Option<GroupByExec<C>> {
     type Error = ConversionError;
 
     fn try_from(value: &QueryContext) -> Result<Option<GroupByExec<C>>, Self::Error> {
-        let where_clause = WhereExprBuilder::new(&value.column_mapping)
-            .build(value.where_expr.clone())?
-            .unwrap_or_else(|| DynProofExpr::new_literal(LiteralValue::Boolean(true)));
-        let table = value.table.map(|table_ref| TableExpr { table_ref }).ok_or(
-            ConversionError::InvalidExpression {
-                expression: "QueryContext has no table_ref".to_owned(),
-            },
-        )?;
-        let resource_id = table.table_ref.resource_id();
-        let group_by_exprs = value
-            .group_by_exprs
-            .iter()
-            .map(|expr| -> Result<ColumnExpr<C>, ConversionError> {
-                value
-                    .column_mapping
-                    .get(expr)
-                    .ok_or(ConversionError::MissingColumn {
-                        identifier: Box::new(*expr),
-                        resource_id: Box::new(resource_id),
-                    })
-                    .map(|column_ref| ColumnExpr::<C>::new(*column_ref))
-            })
-            .collect::<Result<Vec<ColumnExpr<C>>, ConversionError>>()?;
-        // For a query to be provable the result columns must be of one of three kinds below:
+        let (table, where_clause, group_by_exprs) =
+            build_group_by_inputs(value, &value.group_by_exprs)?;
+        // For a query to be provable the result columns must be of one of the kinds below:
         // 1. Group by columns (it is mandatory to have all of them in the correct order)
         // 2. Sum(expr) expressions (it is optional to have any)
-        // 3. count(*) with an alias (it is mandatory to have one and only one)
+        // 3. Max(expr) and then Min(expr) expressions (it is optional to have any)
+        // 4. count(*) with an alias (it is mandatory to have one and only one)
         let num_group_by_columns = group_by_exprs.len();
         let num_result_columns = value.res_aliased_exprs.len();
         if num_result_columns < num_group_by_columns + 1 {
             return Ok(None);
         }
-        let res_group_by_columns = &value.res_aliased_exprs[..num_group_by_columns].to_vec();
-        let sum_expr_columns =
-            &value.res_aliased_exprs[num_group_by_columns..num_result_columns - 1].to_vec();
+        let res_group_by_columns = &value.res_aliased_exprs[..num_group_by_columns];
+        let aggregate_expr_columns =
+            &value.res_aliased_exprs[num_group_by_columns..num_result_columns - 1];
         // Check group by columns
-        let group_by_compliance = value
-            .group_by_exprs
-            .iter()
-            .zip(res_group_by_columns.iter())
-            .all(|(ident, res)| {
-                if let Expression::Column(res_ident) = *res.expr {
-                    res_ident == *ident
-                } else {
-                    false
-                }
-            });
-
-        // Check sums
-        let sum_expr = sum_expr_columns
-            .iter()
-            .map(|res| {
-                if let Expression::Aggregation {
-                    op: AggregationOperator::Sum,
-                    ..
-                } = (*res.expr).clone()
-                {
-                    let res_dyn_proof_expr =
-                        DynProofExprBuilder::new(&value.column_mapping).build(&res.expr);
-                    res_dyn_proof_expr
-                        .ok()
-                        .map(|dyn_proof_expr| AliasedDynProofExpr {
-                            alias: res.alias,
-                            expr: dyn_proof_expr,
-                        })
-                } else {
-                    None
-                }
-            })
-            .collect::<Option<Vec<AliasedDynProofExpr<C>>>>();
+        let group_by_compliance =
+            is_group_by_compliant(&value.group_by_exprs, res_group_by_columns);
+
+        // Check sums, maxima and minima, in that order
+        let num_sums = count_leading_aggregates(aggregate_expr_columns, AggregationOperator::Sum);
+        let (sum_expr_columns, extremum_expr_columns) = aggregate_expr_columns.split_at(num_sums);
+        let num_maxima = count_leading_aggregates(extremum_expr_columns, AggregationOperator::Max);
+        let (max_expr_columns, min_expr_columns) = extremum_expr_columns.split_at(num_maxima);
+        let sum_expr = build_aggregate_exprs(value, sum_expr_columns, AggregationOperator::Sum);
+        let max_expr = build_aggregate_exprs(value, max_expr_columns, AggregationOperator::Max);
+        let min_expr = build_aggregate_exprs(value, min_expr_columns, AggregationOperator::Min);
 
         // Check count(*)
         let count_column = &value.res_aliased_exprs[num_result_columns - 1];
@@ -312,15 +270,219 @@ impl<C: Commitment> TryFrom<&QueryContext> for Option<GroupByExec<C>> {
             }
         );
 
-        if !group_by_compliance || sum_expr.is_none() || !count_column_compliant {
+        let (true, Some(sum_expr), Some(max_expr), Some(min_expr), true) = (
+            group_by_compliance,
+            sum_expr,
+            max_expr,
+            min_expr,
+            count_column_compliant,
+        ) else {
             return Ok(None);
-        }
+        };
         Ok(Some(GroupByExec::new(
             group_by_exprs,
-            sum_expr.expect("the none case was just checked"),
+            sum_expr,
+            max_expr,
+            min_expr,
             count_column.alias,
             table,
             where_clause,
         )))
     }
 }
+
+/// Converts a `QueryContext` with `COUNT(DISTINCT <column>)` aggregates into a `GroupByExec` that
+/// also groups by that column, along with the postprocessing that merges those groups again.
+///
+/// The proof shows exactly which distinct values each group has, so counting them is exact.
+/// We use Some if the query is provable this way and None if it is not
+/// We error out if the query is wrong
+impl<C: Commitment> TryFrom<&QueryContext> for Option<(GroupByExec<C>, GroupByPostprocessing)> {
+    type Error = ConversionError;
+
+    fn try_from(value: &QueryContext) -> Result<Self, Self::Error> {
+        let distinct_columns = value
+            .res_aliased_exprs
+            .iter()
+            .filter_map(|res| match &*res.expr {
+                Expression::Aggregation {
+                    op: AggregationOperator::CountDistinct,
+                    expr,
+                } => match **expr {
+                    Expression::Column(column) => Some(column),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<IndexSet<_>>();
+        // Grouping by several columns would count their distinct combinations instead
+        if distinct_columns.len() != 1 {
+            return Ok(None);
+        }
+        let distinct_column = distinct_columns[0];
+
+        let num_group_by_columns = value.group_by_exprs.len();
+        if value.res_aliased_exprs.len() < num_group_by_columns {
+            return Ok(None);
+        }
+        let (res_group_by_columns, aggregate_expr_columns) =
+            value.res_aliased_exprs.split_at(num_group_by_columns);
+        if !is_group_by_compliant(&value.group_by_exprs, res_group_by_columns) {
+            return Ok(None);
+        }
+        let mut refined_group_by = value.group_by_exprs.clone();
+        if !refined_group_by.contains(&distinct_column) {
+            if aggregate_expr_columns
+                .iter()
+                .any(|res| res.alias == distinct_column)
+            {
+                return Ok(None);
+            }
+            refined_group_by.push(distinct_column);
+        }
+
+        // Aggregate over the refined groups, and then merge each aggregate over the original groups
+        let (mut sums, mut maxima, mut minima) = (Vec::new(), Vec::new(), Vec::new());
+        let mut count_alias = None;
+        let mut merged_exprs = res_group_by_columns.to_vec();
+        for res in aggregate_expr_columns {
+            let Expression::Aggregation { op, .. } = *res.expr else {
+                return Ok(None);
+            };
+            let expr = match op {
+                AggregationOperator::Sum => {
+                    sums.push(res.clone());
+                    Expression::Column(res.alias).sum()
+                }
+                AggregationOperator::Max => {
+                    maxima.push(res.clone());
+                    Expression::Column(res.alias).max()
+                }
+                AggregationOperator::Min => {
+                    minima.push(res.clone());
+                    Expression::Column(res.alias).min()
+                }
+                AggregationOperator::Count => {
+                    Expression::Column(*count_alias.get_or_insert(res.alias)).sum()
+                }
+                AggregationOperator::CountDistinct => Expression::Column(distinct_column).count(),
+                AggregationOperator::First => return Ok(None),
+            };
+            merged_exprs.push(AliasedResultExpr {
+                expr,
+                alias: res.alias,
+            });
+        }
+        let (Some(sum_expr), Some(max_expr), Some(min_expr)) = (
+            build_aggregate_exprs(value, &sums, AggregationOperator::Sum),
+            build_aggregate_exprs(value, &maxima, AggregationOperator::Max),
+            build_aggregate_exprs(value, &minima, AggregationOperator::Min),
+        ) else {
+            return Ok(None);
+        };
+        let count_alias = count_alias.unwrap_or_else(|| {
+            "__count__"
+                .parse()
+                .expect("__count__ is a valid identifier")
+        });
+
+        let (table, where_clause, group_by_exprs) =
+            build_group_by_inputs(value, &refined_group_by)?;
+        let postprocessing =
+            GroupByPostprocessing::try_new(value.group_by_exprs.clone(), merged_exprs)?;
+        Ok(Some((
+            GroupByExec::new(
+                group_by_exprs,
+                sum_expr,
+                max_expr,
+                min_expr,
+                count_alias,
+                table,
+                where_clause,
+            ),
+            postprocessing,
+        )))
+    }
+}
+
+/// The table, `WHERE` clause and grouping columns of a `GroupByExec` over the query's table
+fn build_group_by_inputs<C: Commitment>(
+    value: &QueryContext,
+    group_by: &[Identifier],
+) -> ConversionResult<(TableExpr, DynProofExpr<C>, Vec<ColumnExpr<C>>)> {
+    let where_clause = WhereExprBuilder::new(&value.column_mapping)
+        .build(value.where_expr.clone())?
+        .unwrap_or_else(|| DynProofExpr::new_literal(LiteralValue::Boolean(true)));
+    let table = value.table.map(|table_ref| TableExpr { table_ref }).ok_or(
+        ConversionError::InvalidExpression {
+            expression: "QueryContext has no table_ref".to_owned(),
+        },
+    )?;
+    let resource_id = table.table_ref.resource_id();
+    let group_by_exprs = group_by
+        .iter()
+        .map(|expr| -> Result<ColumnExpr<C>, ConversionError> {
+            value
+                .column_mapping
+                .get(expr)
+                .ok_or(ConversionError::MissingColumn {
+                    identifier: Box::new(*expr),
+                    resource_id: Box::new(resource_id),
+                })
+                .map(|column_ref| ColumnExpr::<C>::new(*column_ref))
+        })
+        .collect::<Result<Vec<ColumnExpr<C>>, ConversionError>>()?;
+    Ok((table, where_clause, group_by_exprs))
+}
+
+/// Whether the leading result columns are exactly the group by columns, in order
+fn is_group_by_compliant(
+    group_by: &[Identifier],
+    res_group_by_columns: &[AliasedResultExpr],
+) -> bool {
+    group_by
+        .iter()
+        .zip(res_group_by_columns)
+        .all(|(ident, res)| {
+            if let Expression::Column(res_ident) = *res.expr {
+                res_ident == *ident
+            } else {
+                false
+            }
+        })
+}
+
+/// The number of leading result columns that are aggregations with the operator `op`
+fn count_leading_aggregates(columns: &[AliasedResultExpr], op: AggregationOperator) -> usize {
+    columns
+        .iter()
+        .take_while(
+            |res| matches!(*res.expr, Expression::Aggregation { op: res_op, .. } if res_op == op),
+        )
+        .count()
+}
+
+/// Build the provable expressions of result columns that are all aggregations with the operator `op`
+///
+/// Returns `None` if any of the columns is another expression or is not provable.
+fn build_aggregate_exprs<C: Commitment>(
+    context: &QueryContext,
+    columns: &[AliasedResultExpr],
+    op: AggregationOperator,
+) -> Option<Vec<AliasedDynProofExpr<C>>> {
+    if count_leading_aggregates(columns, op) != columns.len() {
+        return None;
+    }
+    columns
+        .iter()
+        .map(|res| {
+            DynProofExprBuilder::new(&context.column_mapping)
+                .build(&res.expr)
+                .ok()
+                .map(|dyn_proof_expr| AliasedDynProofExpr {
+                    alias: res.alias,
+                    expr: dyn_proof_expr,
+                })
+        })
+        .collect()
+}
@@ -1,4 +1,4 @@
-use super::{ConversionError, ConversionResult, QueryContext};
+use super::{AccessPolicy, ConversionError, ConversionResult, QueryContext};
 use crate::base::{
     database::{
         try_add_subtract_column_types, try_multiply_column_types, ColumnRef, ColumnType,
@@ -50,6 +50,17 @@ impl<'a> QueryContextBuilder<'a> {
         self
     }
 
+    /// Restrict `where_expr` to the rows of the table that `access_policy` permits.
+    ///
+    /// The table must already have been visited.
+    pub fn restrict_to_permitted_rows(
+        &self,
+        where_expr: Option<Box<Expression>>,
+        access_policy: &AccessPolicy,
+    ) -> Option<Box<Expression>> {
+        access_policy.restrict(self.context.get_table_ref(), where_expr)
+    }
+
     pub fn visit_where_expr(
         mut self,
         mut where_expr: Option<Box<Expression>>,
@@ -138,6 +149,12 @@ impl<'a> QueryContextBuilder<'a> {
             Expression::Literal(literal) => self.visit_literal(literal),
             Expression::Column(_) => self.visit_column_expr(expr),
             Expression::Unary { op, expr } => self.visit_unary_expr(*op, expr),
+            Expression::InList { expr, list } => self.visit_in_list_expr(expr, list),
+            Expression::Case {
+                predicate,
+                then,
+                otherwise,
+            } => self.visit_case_expr(predicate, then, otherwise),
             Expression::Binary { op, left, right } => self.visit_binary_expr(*op, left, right),
             Expression::Aggregation { op, expr } => self.visit_agg_expr(*op, expr),
         }
@@ -173,6 +190,7 @@ impl<'a> QueryContextBuilder<'a> {
             | BinaryOperator::Division
             | BinaryOperator::Subtract
             | BinaryOperator::Add => Ok(left_dtype),
+            BinaryOperator::Modulo => Ok(ColumnType::BigInt),
         }
     }
 
@@ -195,6 +213,36 @@ impl<'a> QueryContextBuilder<'a> {
         }
     }
 
+    fn visit_in_list_expr(
+        &mut self,
+        expr: &Expression,
+        list: &[Literal],
+    ) -> ConversionResult<ColumnType> {
+        let dtype = self.visit_expr(expr)?;
+        for literal in list {
+            check_dtypes(dtype, self.visit_literal(literal)?, BinaryOperator::Equal)?;
+        }
+        Ok(ColumnType::Boolean)
+    }
+
+    fn visit_case_expr(
+        &mut self,
+        predicate: &Expression,
+        then: &Expression,
+        otherwise: &Expression,
+    ) -> ConversionResult<ColumnType> {
+        let predicate_dtype = self.visit_expr(predicate)?;
+        if predicate_dtype != ColumnType::Boolean {
+            return Err(ConversionError::InvalidDataType {
+                expected: ColumnType::Boolean,
+                actual: predicate_dtype,
+            });
+        }
+        let then_dtype = self.visit_expr(then)?;
+        let otherwise_dtype = self.visit_expr(otherwise)?;
+        try_case_column_type(then_dtype, otherwise_dtype)
+    }
+
     fn visit_agg_expr(
         &mut self,
         op: AggregationOperator,
@@ -203,19 +251,30 @@ impl<'a> QueryContextBuilder<'a> {
         self.context.set_in_agg_scope(true)?;
 
         let expr_dtype = self.visit_expr(expr)?;
+        let is_count = matches!(
+            op,
+            AggregationOperator::Count | AggregationOperator::CountDistinct
+        );
 
         // We only support sum/max/min aggregations on numeric columns.
-        if op != AggregationOperator::Count && expr_dtype == ColumnType::VarChar {
+        if !is_count && expr_dtype == ColumnType::VarChar {
             return Err(ConversionError::non_numeric_expr_in_agg(
                 expr_dtype.to_string(),
                 op.to_string(),
             ));
         }
 
+        // COUNT(DISTINCT) is proven by grouping by its argument, so that has to be a column.
+        if op == AggregationOperator::CountDistinct && !matches!(expr, Expression::Column(_)) {
+            return Err(ConversionError::InvalidExpression {
+                expression: "COUNT(DISTINCT) is only supported on a column".to_string(),
+            });
+        }
+
         self.context.set_in_agg_scope(false)?;
 
         // Count aggregation always results in an integer type
-        if op == AggregationOperator::Count {
+        if is_count {
             Ok(ColumnType::BigInt)
         } else {
             Ok(expr_dtype)
@@ -260,6 +319,34 @@ impl<'a> QueryContextBuilder<'a> {
     }
 }
 
+/// The type of `CASE WHEN p THEN a ELSE b END`, where `a` has type `then_dtype` and `b` has
+/// type `otherwise_dtype`.
+///
+/// The branches must be numeric, and either have the same type or both be integers, in which case
+/// the result has the wider of the two types.
+pub(crate) fn try_case_column_type(
+    then_dtype: ColumnType,
+    otherwise_dtype: ColumnType,
+) -> ConversionResult<ColumnType> {
+    for dtype in [then_dtype, otherwise_dtype] {
+        if !dtype.is_numeric() {
+            return Err(ConversionError::InvalidDataType {
+                expected: ColumnType::BigInt,
+                actual: dtype,
+            });
+        }
+    }
+    if then_dtype == otherwise_dtype {
+        return Ok(then_dtype);
+    }
+    then_dtype
+        .max_integer_type(&otherwise_dtype)
+        .ok_or_else(|| ConversionError::DataTypeMismatch {
+            left_type: then_dtype.to_string(),
+            right_type: otherwise_dtype.to_string(),
+        })
+}
+
 /// TODO: add docs
 pub(crate) fn type_check_binary_operation(
     left_dtype: &ColumnType,
@@ -314,6 +401,7 @@ pub(crate) fn type_check_binary_operation(
         }
         BinaryOperator::Multiply => try_multiply_column_types(*left_dtype, *right_dtype).is_ok(),
         BinaryOperator::Division => left_dtype.is_numeric() && right_dtype.is_numeric(),
+        BinaryOperator::Modulo => left_dtype.is_integer() && right_dtype.is_integer(),
     }
 }
 
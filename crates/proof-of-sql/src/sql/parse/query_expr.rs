@@ -1,4 +1,4 @@
-use super::{EnrichedExpr, FilterExecBuilder, QueryContextBuilder};
+use super::{AccessPolicy, EnrichedExpr, FilterExecBuilder, QueryContextBuilder};
 use crate::{
     base::{commitment::Commitment, database::SchemaAccessor},
     sql::{
@@ -48,6 +48,25 @@ impl<C: Commitment> QueryExpr<C> {
         ast: SelectStatement,
         default_schema: Identifier,
         schema_accessor: &dyn SchemaAccessor,
+    ) -> ConversionResult<Self> {
+        Self::try_new_with_access_policy(
+            ast,
+            default_schema,
+            schema_accessor,
+            &AccessPolicy::default(),
+        )
+    }
+
+    /// Parse an intermediate AST `SelectStatement` into a `QueryExpr` that only reads the rows
+    /// permitted by `access_policy`.
+    ///
+    /// The prover and the verifier must plan the query with the same policy.
+    #[allow(clippy::too_many_lines)]
+    pub fn try_new_with_access_policy(
+        ast: SelectStatement,
+        default_schema: Identifier,
+        schema_accessor: &dyn SchemaAccessor,
+        access_policy: &AccessPolicy,
     ) -> ConversionResult<Self> {
         let context = match *ast.expr {
             SetExpression::Query {
@@ -55,14 +74,18 @@ impl<C: Commitment> QueryExpr<C> {
                 from,
                 where_expr,
                 group_by,
-            } => QueryContextBuilder::new(schema_accessor)
-                .visit_table_expr(&from, default_schema)
-                .visit_group_by_exprs(group_by)?
-                .visit_result_exprs(result_exprs)?
-                .visit_where_expr(where_expr)?
-                .visit_order_by_exprs(ast.order_by)
-                .visit_slice_expr(ast.slice)
-                .build()?,
+            } => {
+                let builder = QueryContextBuilder::new(schema_accessor)
+                    .visit_table_expr(&from, default_schema);
+                let where_expr = builder.restrict_to_permitted_rows(where_expr, access_policy);
+                builder
+                    .visit_group_by_exprs(group_by)?
+                    .visit_result_exprs(result_exprs)?
+                    .visit_where_expr(where_expr)?
+                    .visit_order_by_exprs(ast.order_by)
+                    .visit_slice_expr(ast.slice)
+                    .build()?
+            }
         };
         let result_aliased_exprs = context.get_aliased_result_exprs()?.to_vec();
         let group_by = context.get_group_by_exprs();
@@ -86,6 +109,17 @@ impl<C: Commitment> QueryExpr<C> {
                     proof_expr: DynProofPlan::GroupBy(group_by_expr),
                     postprocessing,
                 })
+            } else if let Some((group_by_expr, group_by_postprocessing)) =
+                Option::<(GroupByExec<C>, GroupByPostprocessing)>::try_from(&context)?
+            {
+                postprocessing.insert(
+                    0,
+                    OwnedTablePostprocessing::new_group_by(group_by_postprocessing),
+                );
+                Ok(Self {
+                    proof_expr: DynProofPlan::GroupBy(group_by_expr),
+                    postprocessing,
+                })
             } else {
                 let raw_enriched_exprs = result_aliased_exprs
                     .iter()
@@ -2,8 +2,9 @@ use super::ConversionError;
 use crate::{
     base::{
         commitment::naive_commitment::NaiveCommitment,
-        database::{ColumnType, TableRef, TestSchemaAccessor},
+        database::{ColumnType, LiteralValue, TableRef, TestSchemaAccessor},
         map::{indexmap, IndexMap, IndexSet},
+        math::decimal::Precision,
     },
     sql::{
         parse::QueryExpr,
@@ -117,6 +118,143 @@ fn we_can_convert_an_ast_with_one_column_and_a_filter_by_a_string_literal() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_fold_boolean_literals_in_the_where_clause() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::Boolean,
+        },
+    );
+    let expected_where = |where_clause| {
+        QueryExpr::new(
+            filter(cols_expr_plan(t, &["a"], &accessor), tab(t), where_clause),
+            vec![],
+        )
+    };
+    for (query, where_clause) in [
+        (
+            "select a from sxt_tab where a = 3 and true",
+            equal(column(t, "a", &accessor), const_bigint(3)),
+        ),
+        (
+            "select a from sxt_tab where true and (a = 3 or false)",
+            equal(column(t, "a", &accessor), const_bigint(3)),
+        ),
+        ("select a from sxt_tab where b and false", const_bool(false)),
+        ("select a from sxt_tab where true or b", const_bool(true)),
+        (
+            "select a from sxt_tab where not (b and false)",
+            const_bool(true),
+        ),
+        (
+            "select a from sxt_tab where not b and not false",
+            not(column(t, "b", &accessor)),
+        ),
+    ] {
+        assert_eq!(
+            query_to_provable_ast(t, query, &accessor),
+            expected_where(where_clause)
+        );
+    }
+}
+
+#[test]
+fn we_cannot_fold_boolean_literals_with_non_boolean_operands() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    invalid_query_to_provable_ast(t, "select a from sxt_tab where a and true", &accessor);
+    invalid_query_to_provable_ast(t, "select a from sxt_tab where false or a", &accessor);
+}
+
+#[test]
+fn we_can_convert_an_ast_with_an_in_list_filter() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::VarChar,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select a from sxt_tab where not (b in ('x', 'yz'))",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            not(in_list(
+                column(t, "b", &accessor),
+                [
+                    LiteralValue::VarChar(("x".to_string(), "x".into())),
+                    LiteralValue::VarChar(("yz".to_string(), "yz".into())),
+                ],
+            )),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_convert_an_ast_with_an_in_list_of_mismatched_types() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    invalid_query_to_provable_ast(t, "select a from sxt_tab where a in (1, 'x')", &accessor);
+}
+
+#[test]
+fn we_can_convert_an_ast_with_a_modulo_filter() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(t, "select a from sxt_tab where a % 100 = 0", &accessor);
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            equal(modulo(column(t, "a", &accessor), 100), const_bigint(0)),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_convert_an_ast_with_an_invalid_modulo() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::BigInt,
+            "d".parse().unwrap() => ColumnType::Decimal75(Precision::new(5).unwrap(), 2),
+        },
+    );
+    invalid_query_to_provable_ast(t, "select a from sxt_tab where a % 0 = 0", &accessor);
+    invalid_query_to_provable_ast(t, "select a from sxt_tab where a % b = 0", &accessor);
+    invalid_query_to_provable_ast(t, "select a from sxt_tab where d % 3 = 0", &accessor);
+}
+
 #[test]
 fn we_cannot_convert_an_ast_with_duplicate_aliases() {
     let t = "sxt.sxt_tab".parse().unwrap();
@@ -1033,6 +1171,58 @@ fn we_can_do_provable_group_by_with_two_group_by_columns() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_do_provable_group_by_with_max_and_min() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "bonus".parse().unwrap() => ColumnType::Int,
+            "department".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select department, sum(salary) as total_salary, max(salary) as max_salary, min(bonus) as min_bonus, count(*) as num_employee from employees group by department",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        group_by_with_extrema(
+            cols_expr(t, &["department"], &accessor),
+            vec![sum_expr(column(t, "salary", &accessor), "total_salary")],
+            vec![max_expr(column(t, "salary", &accessor), "max_salary")],
+            vec![min_expr(column(t, "bonus", &accessor), "min_bonus")],
+            "num_employee",
+            tab(t),
+            const_bool(true),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_do_not_prove_max_and_min_out_of_order_or_over_decimals() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "bonus".parse().unwrap() => ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+            "department".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    for query in [
+        "select department, min(salary) as min_salary, max(salary) as max_salary, count(*) as num_employee from employees group by department",
+        "select department, max(salary) as max_salary, sum(salary) as total_salary, count(*) as num_employee from employees group by department",
+        "select department, max(bonus) as max_bonus, count(*) as num_employee from employees group by department",
+    ] {
+        let ast = query_to_provable_ast(t, query, &accessor);
+        assert!(matches!(ast.proof_expr(), DynProofPlan::Filter(_)));
+    }
+}
+
 #[test]
 fn we_can_do_provable_group_by_with_two_sums_and_filter() {
     let t = "sxt.employees".parse().unwrap();
@@ -1065,6 +1255,113 @@ fn we_can_do_provable_group_by_with_two_sums_and_filter() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_do_provable_count_distinct_with_group_by() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "department".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select department, sum(salary) as total_salary, count(distinct salary) as num_salaries, count(*) as num_employee from employees where salary >= 1 group by department",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        group_by(
+            cols_expr(t, &["department", "salary"], &accessor),
+            vec![sum_expr(column(t, "salary", &accessor), "total_salary")],
+            "num_employee",
+            tab(t),
+            gte(column(t, "salary", &accessor), const_bigint(1)),
+        ),
+        vec![group_by_postprocessing(
+            &["department"],
+            &[
+                aliased_expr(col("department"), "department"),
+                aliased_expr(sum(col("total_salary")), "total_salary"),
+                aliased_expr(count(col("salary")), "num_salaries"),
+                aliased_expr(sum(col("num_employee")), "num_employee"),
+            ],
+        )],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_do_provable_count_distinct_without_group_by() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "name".parse().unwrap() => ColumnType::VarChar,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select count(distinct name) as num_names from employees",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        group_by(
+            cols_expr(t, &["name"], &accessor),
+            vec![],
+            "__count__",
+            tab(t),
+            const_bool(true),
+        ),
+        vec![group_by_postprocessing(
+            &[],
+            &[aliased_expr(count(col("name")), "num_names")],
+        )],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_count_distinct_values_of_an_expression() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select count(distinct salary + 1) from sxt.employees")
+        .unwrap();
+    let result = QueryExpr::<NaiveCommitment>::try_new(intermediate_ast, t.schema_id(), &accessor);
+    assert!(matches!(
+        result,
+        Err(ConversionError::InvalidExpression { .. })
+    ));
+}
+
+#[test]
+fn we_do_not_prove_count_distinct_over_several_columns() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "bonus".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select count(distinct salary) as a, count(distinct bonus) as b from sxt.employees")
+        .unwrap();
+    let result = QueryExpr::<NaiveCommitment>::try_new(intermediate_ast, t.schema_id(), &accessor);
+    assert!(matches!(
+        result,
+        Err(ConversionError::PostprocessingError {
+            source: PostprocessingError::UnsupportedCountDistinct { .. }
+        })
+    ));
+}
+
 ///////////////////////////
 // Group By Expressions - Postprocessing
 ///////////////////////////
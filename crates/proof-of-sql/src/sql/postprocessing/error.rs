@@ -47,6 +47,14 @@ pub enum PostprocessingError {
         /// The underlying source error
         source: crate::base::database::OwnedColumnError,
     },
+    /// `COUNT(DISTINCT)` that is not proven by grouping by its argument
+    #[snafu(display(
+        "COUNT(DISTINCT) is only supported alongside provable aggregations: {error}"
+    ))]
+    UnsupportedCountDistinct {
+        /// The unsupported aggregation
+        error: String,
+    },
     /// Nested aggregation in `GROUP BY` clause
     #[snafu(display("Nested aggregation in `GROUP BY` clause: {error}"))]
     NestedAggregationInGroupByClause {
@@ -38,7 +38,16 @@ fn contains_nested_aggregation(expr: &Expression, is_agg: bool) -> bool {
         Expression::Binary { left, right, .. } => {
             contains_nested_aggregation(left, is_agg) || contains_nested_aggregation(right, is_agg)
         }
-        Expression::Unary { expr, .. } => contains_nested_aggregation(expr, is_agg),
+        Expression::Unary { expr, .. } | Expression::InList { expr, .. } => {
+            contains_nested_aggregation(expr, is_agg)
+        }
+        Expression::Case {
+            predicate,
+            then,
+            otherwise,
+        } => [predicate, then, otherwise]
+            .iter()
+            .any(|expr| contains_nested_aggregation(expr, is_agg)),
     }
 }
 
@@ -55,7 +64,17 @@ fn get_free_identifiers_from_expr(expr: &Expression) -> IndexSet<Identifier> {
             left_identifiers.extend(right_identifiers);
             left_identifiers
         }
-        Expression::Unary { expr, .. } => get_free_identifiers_from_expr(expr),
+        Expression::Unary { expr, .. } | Expression::InList { expr, .. } => {
+            get_free_identifiers_from_expr(expr)
+        }
+        Expression::Case {
+            predicate,
+            then,
+            otherwise,
+        } => [predicate, then, otherwise]
+            .iter()
+            .flat_map(|expr| get_free_identifiers_from_expr(expr))
+            .collect(),
     }
 }
 
@@ -104,6 +123,30 @@ fn get_aggregate_and_remainder_expressions(
                 expr: Box::new(remainder),
             }
         }
+        Expression::InList { expr, list } => {
+            let remainder = get_aggregate_and_remainder_expressions(*expr, aggregation_expr_map);
+            Expression::InList {
+                expr: Box::new(remainder),
+                list,
+            }
+        }
+        Expression::Case {
+            predicate,
+            then,
+            otherwise,
+        } => {
+            let predicate_remainder =
+                get_aggregate_and_remainder_expressions(*predicate, aggregation_expr_map);
+            let then_remainder =
+                get_aggregate_and_remainder_expressions(*then, aggregation_expr_map);
+            let otherwise_remainder =
+                get_aggregate_and_remainder_expressions(*otherwise, aggregation_expr_map);
+            Expression::Case {
+                predicate: Box::new(predicate_remainder),
+                then: Box::new(then_remainder),
+                otherwise: Box::new(otherwise_remainder),
+            }
+        }
     }
 }
 
@@ -164,6 +207,14 @@ impl GroupByPostprocessing {
                 )
             })
             .collect::<PostprocessingResult<Vec<AliasedResultExpr>>>()?;
+        if let Some((_, expr)) = aggregation_expr_map
+            .keys()
+            .find(|(op, _)| *op == AggregationOperator::CountDistinct)
+        {
+            return Err(PostprocessingError::UnsupportedCountDistinct {
+                error: format!("{expr:?}"),
+            });
+        }
         let group_by_identifiers = Vec::from_iter(IndexSet::from_iter(by_ids));
         Ok(Self {
             remainder_exprs,
@@ -255,7 +306,7 @@ impl<S: Scalar> PostprocessingStep<S> for GroupByPostprocessing {
                     .map(|(id, c)| (*id, Column::<S>::from_owned_column(c, &alloc)))
                     .unzip()
             });
-        let aggregation_results = aggregate_columns(
+        let mut aggregation_results = aggregate_columns(
             &alloc,
             &group_by_ins,
             &sum_columns,
@@ -263,6 +314,16 @@ impl<S: Scalar> PostprocessingStep<S> for GroupByPostprocessing {
             &min_columns,
             &selection_in,
         )?;
+        // Without GROUP BY there is exactly one row even when there is nothing to aggregate,
+        // and since `NULL` is not supported its aggregates are zero
+        if self.group_by_identifiers.is_empty() && aggregation_results.count_column.is_empty() {
+            let zero = &*alloc.alloc_slice_fill_default(1);
+            let some_zero = &*alloc.alloc_slice_fill_copy(1, Some(S::ZERO));
+            aggregation_results.sum_columns = vec![zero; sum_columns.len()];
+            aggregation_results.max_columns = vec![some_zero; max_columns.len()];
+            aggregation_results.min_columns = vec![some_zero; min_columns.len()];
+            aggregation_results.count_column = alloc.alloc_slice_fill_default(1);
+        }
         // Finally do another round of evaluation to get the final result
         // Gather the results into a new OwnedTable
         let group_by_outs = aggregation_results
@@ -298,3 +298,55 @@ fn we_can_do_complex_group_bys() {
     let actual_table = apply_postprocessing_steps(table, &postprocessing).unwrap();
     assert_eq!(actual_table, expected_table);
 }
+
+#[test]
+fn we_cannot_do_count_distinct_in_group_by_postprocessing() {
+    // SELECT COUNT(DISTINCT a) as res FROM tab
+    let res =
+        GroupByPostprocessing::try_new(vec![], vec![aliased_expr(count_distinct(col("a")), "res")]);
+    assert!(matches!(
+        res,
+        Err(PostprocessingError::UnsupportedCountDistinct { .. })
+    ));
+}
+
+#[test]
+fn we_get_one_row_when_aggregating_no_rows_without_a_group_by() {
+    // SELECT SUM(b) as sum_b, MAX(b) as max_b, MIN(c) as min_c, COUNT(a) as count_a FROM tab
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        int128("a", [0_i128; 0]),
+        bigint("b", [0_i64; 0]),
+        smallint("c", [0_i16; 0]),
+    ]);
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_postprocessing(
+        &[],
+        &[
+            aliased_expr(sum(col("b")), "sum_b"),
+            aliased_expr(max(col("b")), "max_b"),
+            aliased_expr(min(col("c")), "min_c"),
+            aliased_expr(count(col("a")), "count_a"),
+        ],
+    )];
+    let expected_table = owned_table([
+        bigint("sum_b", [0_i64]),
+        bigint("max_b", [0_i64]),
+        smallint("min_c", [0_i16]),
+        bigint("count_a", [0_i64]),
+    ]);
+    let actual_table = apply_postprocessing_steps(table, &postprocessing).unwrap();
+    assert_eq!(actual_table, expected_table);
+
+    // SELECT a, COUNT(b) as count_b FROM tab GROUP BY a
+    let table: OwnedTable<Curve25519Scalar> =
+        owned_table([int128("a", [0_i128; 0]), bigint("b", [0_i64; 0])]);
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_postprocessing(
+        &["a"],
+        &[
+            aliased_expr(col("a"), "a"),
+            aliased_expr(count(col("b")), "count_b"),
+        ],
+    )];
+    let expected_table = owned_table([int128("a", [0_i128; 0]), bigint("count_b", [0_i64; 0])]);
+    let actual_table = apply_postprocessing_steps(table, &postprocessing).unwrap();
+    assert_eq!(actual_table, expected_table);
+}
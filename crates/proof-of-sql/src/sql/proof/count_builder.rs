@@ -1,5 +1,5 @@
 use crate::{
-    base::{bit::BitDistribution, proof::ProofError},
+    base::{bit::BitDistribution, database::ColumnRef, map::IndexSet, proof::ProofError},
     sql::proof::ProofCounts,
 };
 use core::cmp::max;
@@ -8,6 +8,8 @@ use core::cmp::max;
 pub struct CountBuilder<'a> {
     bit_distributions: &'a [BitDistribution],
     counts: ProofCounts,
+    column_mles: IndexSet<ColumnRef>,
+    assume_varying_bits: bool,
 }
 
 impl<'a> CountBuilder<'a> {
@@ -15,6 +17,19 @@ impl<'a> CountBuilder<'a> {
         Self {
             bit_distributions,
             counts: ProofCounts::default(),
+            column_mles: IndexSet::default(),
+            assume_varying_bits: false,
+        }
+    }
+
+    /// Create a builder for counting a query before it is proven, when no bit distributions exist yet.
+    ///
+    /// Every bit distribution is assumed to have all of its bits varying, so the counts are an
+    /// upper bound on those of any proof of the query.
+    pub fn new_worst_case() -> Self {
+        Self {
+            assume_varying_bits: true,
+            ..Self::new(&[])
         }
     }
 
@@ -23,7 +38,14 @@ impl<'a> CountBuilder<'a> {
     /// This method provides access to the bit distributions of a proof during the counting
     /// pass of verification.
     pub fn consume_bit_distribution(&mut self) -> Result<BitDistribution, ProofError> {
-        if self.bit_distributions.is_empty() {
+        if self.assume_varying_bits {
+            // the low 129 bits and the sign bit, the widest distribution an expression may have
+            let mask = [u64::MAX, u64::MAX, 1, 1 << 63];
+            Ok(BitDistribution {
+                or_all: mask,
+                vary_mask: mask,
+            })
+        } else if self.bit_distributions.is_empty() {
             Err(ProofError::VerificationError {
                 error: "expected prover to provide bit distribution",
             })
@@ -46,6 +68,15 @@ impl<'a> CountBuilder<'a> {
         self.counts.anchored_mles += cnt;
     }
 
+    /// Count the anchored MLE of a table column.
+    ///
+    /// A column referenced more than once is only counted the first time.
+    pub fn count_column_mle(&mut self, column_ref: ColumnRef) {
+        if self.column_mles.insert(column_ref) {
+            self.counts.anchored_mles += 1;
+        }
+    }
+
     pub fn count_intermediate_mles(&mut self, cnt: usize) {
         self.counts.intermediate_mles += cnt;
     }
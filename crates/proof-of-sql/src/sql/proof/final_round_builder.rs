@@ -5,6 +5,8 @@ use super::{
 use crate::base::{
     bit::BitDistribution,
     commitment::{Commitment, CommittableColumn, VecCommitmentExt},
+    database::{Column, ColumnRef},
+    map::IndexSet,
     polynomial::{CompositePolynomial, MultilinearExtension},
     scalar::Scalar,
 };
@@ -14,10 +16,12 @@ use num_traits::Zero;
 /// Track components used to form a query's proof
 pub struct FinalRoundBuilder<'a, S: Scalar> {
     table_length: usize,
+    range_length: usize,
     num_sumcheck_variables: usize,
     bit_distributions: Vec<BitDistribution>,
     commitment_descriptor: Vec<CommittableColumn<'a>>,
     pcs_proof_mles: Vec<Box<dyn MultilinearExtension<S> + 'a>>,
+    column_mles: IndexSet<ColumnRef>,
     sumcheck_subpolynomials: Vec<SumcheckSubpolynomial<'a, S>>,
     /// The challenges used in creation of the constraints in the proof.
     /// Specifically, these are the challenges that the verifier sends to
@@ -32,15 +36,18 @@ pub struct FinalRoundBuilder<'a, S: Scalar> {
 impl<'a, S: Scalar> FinalRoundBuilder<'a, S> {
     pub fn new(
         table_length: usize,
+        range_length: usize,
         num_sumcheck_variables: usize,
         post_result_challenges: Vec<S>,
     ) -> Self {
         Self {
             table_length,
+            range_length,
             num_sumcheck_variables,
             bit_distributions: Vec::new(),
             commitment_descriptor: Vec::new(),
             pcs_proof_mles: Vec::new(),
+            column_mles: IndexSet::default(),
             sumcheck_subpolynomials: Vec::new(),
             post_result_challenges,
         }
@@ -71,6 +78,15 @@ impl<'a, S: Scalar> FinalRoundBuilder<'a, S> {
         self.pcs_proof_mles.push(Box::new(data));
     }
 
+    /// Produce the anchored MLE of a table column.
+    ///
+    /// A column referenced more than once is only included in the proof the first time.
+    pub fn produce_column_mle(&mut self, column_ref: ColumnRef, column: Column<'a, S>) {
+        if self.column_mles.insert(column_ref) {
+            self.produce_anchored_mle(column);
+        }
+    }
+
     /// Produce an MLE for a intermediate computed column that we can reference in sumcheck.
     ///
     /// Because the verifier doesn't have access to the MLE's commitment, we will need to
@@ -161,7 +177,7 @@ impl<'a, S: Scalar> FinalRoundBuilder<'a, S> {
     )]
     pub fn fold_pcs_proof_mles(&self, multipliers: &[S]) -> Vec<S> {
         assert_eq!(multipliers.len(), self.pcs_proof_mles.len());
-        let mut res = vec![Zero::zero(); self.table_length];
+        let mut res = vec![Zero::zero(); self.range_length];
         for (multiplier, evaluator) in multipliers.iter().zip(self.pcs_proof_mles.iter()) {
             evaluator.mul_add(&mut res, multiplier);
         }
@@ -24,13 +24,19 @@ mod provable_result_column;
 pub(crate) use provable_result_column::ProvableResultColumn;
 
 mod provable_query_result;
-pub use provable_query_result::ProvableQueryResult;
+pub use provable_query_result::{ProvableQueryResult, ResultError};
 
 mod sumcheck_mle_evaluations;
 pub(crate) use sumcheck_mle_evaluations::SumcheckMleEvaluations;
 #[cfg(test)]
 mod sumcheck_mle_evaluations_test;
 
+mod sumcheck_shape;
+pub use sumcheck_shape::{expected_sumcheck_degree, expected_sumcheck_shape, SumcheckShape};
+
+mod proof_cost;
+pub use proof_cost::{estimate_proof_cost, ProofCostEstimate};
+
 mod sumcheck_random_scalars;
 pub(crate) use sumcheck_random_scalars::SumcheckRandomScalars;
 
@@ -39,13 +45,33 @@ pub use proof_plan::ProofPlan;
 pub(crate) use proof_plan::{HonestProver, ProverEvaluate, ProverHonestyMarker};
 
 mod query_proof;
-pub use query_proof::QueryProof;
+pub use query_proof::{
+    QueryProof, QueryProofDecodeError, QueryProofReadLimits, QueryProofSize, QueryProofSummary,
+    DEFAULT_MAX_SUMCHECK_VARIABLES, DEFAULT_QUERY_PROOF_READ_LIMITS, QUERY_PROOF_ENCODING_VERSION,
+};
 #[cfg(all(test, feature = "blitzar"))]
 mod query_proof_test;
 
+#[cfg(any(test, feature = "test"))]
+pub mod query_proof_mutation;
+
+mod proof_envelope;
+pub use proof_envelope::{
+    ProofEnvelope, ProofEnvelopeDecodeError, PROOF_ENVELOPE_ENCODING_VERSION,
+};
+
 mod query_result;
 pub use query_result::{QueryData, QueryError, QueryResult};
 
+mod verify_trace;
+pub use verify_trace::VerifyTrace;
+
+mod proof_options;
+pub use proof_options::{ProveOptions, VerifyOptions};
+
+mod verify_serialized;
+pub use verify_serialized::{verify_serialized, SerializedVerificationError};
+
 mod sumcheck_subpolynomial;
 pub(crate) use sumcheck_subpolynomial::{
     SumcheckSubpolynomial, SumcheckSubpolynomialTerm, SumcheckSubpolynomialType,
@@ -0,0 +1,51 @@
+use super::{sumcheck_shape::worst_case_counts, ProofPlan, SumcheckShape};
+use crate::base::{commitment::Commitment, database::MetadataAccessor, proof::ProofError};
+
+/// A prediction of the work needed to prove a query, computed from its plan and table lengths.
+///
+/// The cost model has two parts, which can be calibrated separately against measured times:
+/// - Committing: the prover commits to `commitments` intermediate columns of `table_length` rows
+///   each, so this part scales with `commitments * table_length` multi-scalar-multiplication terms.
+/// - Field arithmetic: in every sumcheck round, each subpolynomial is evaluated at `degree + 1`
+///   points over the remaining half of the table, and the evaluation proof folds every MLE it
+///   opens over the whole table. Summed over all rounds this is about `field_operations`.
+///
+/// Both are upper bounds in the same sense as [`expected_sumcheck_shape`](super::expected_sumcheck_shape),
+/// and grow monotonically with the table length and the complexity of the query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofCostEstimate {
+    /// The number of rows the proof covers
+    pub table_length: usize,
+    /// The degree and number of rounds of the sumcheck argument
+    pub sumcheck: SumcheckShape,
+    /// The number of sumcheck subpolynomials
+    pub sumcheck_subpolynomials: usize,
+    /// The number of intermediate columns the prover commits to
+    pub commitments: usize,
+    /// The number of MLEs whose evaluations the evaluation proof opens
+    pub pcs_proof_mles: usize,
+    /// The estimated number of field operations in the sumcheck argument and evaluation proof
+    pub field_operations: usize,
+}
+
+/// Estimate the cost of proving `expr` without touching any data.
+///
+/// See [`ProofCostEstimate`] for the cost model.
+pub fn estimate_proof_cost<C: Commitment>(
+    expr: &impl ProofPlan<C>,
+    accessor: &impl MetadataAccessor,
+) -> Result<ProofCostEstimate, ProofError> {
+    let (counts, sumcheck) = worst_case_counts(expr, accessor)?;
+    let table_length = expr.get_length(accessor);
+    let pcs_proof_mles = counts.anchored_mles + counts.intermediate_mles;
+    let field_operations = (1 << sumcheck.rounds)
+        * (counts.sumcheck_subpolynomials * (sumcheck.degree + 1) + pcs_proof_mles);
+    Ok(ProofCostEstimate {
+        table_length,
+        sumcheck,
+        sumcheck_subpolynomials: counts.sumcheck_subpolynomials,
+        commitments: counts.intermediate_mles,
+        pcs_proof_mles,
+        field_operations,
+    })
+}
@@ -0,0 +1,159 @@
+use super::{ProvableQueryResult, QueryProof, QueryResult};
+use crate::{
+    base::{commitment::CommitmentEvaluationProof, database::CommitmentAccessor},
+    sql::parse::QueryExpr,
+};
+use alloc::vec::Vec;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use snafu::Snafu;
+use tiny_keccak::{Hasher, Keccak};
+
+/// The bytes every encoded [`ProofEnvelope`] starts with.
+const ENVELOPE_MAGIC: [u8; 4] = *b"PSQE";
+
+/// The version of the [`ProofEnvelope`] encoding written by [`ProofEnvelope::to_bytes`].
+///
+/// This must be bumped whenever the serialized layout of an envelope changes.
+pub const PROOF_ENVELOPE_ENCODING_VERSION: u8 = 1;
+
+/// The number of bytes in the checksum that ends every encoded [`ProofEnvelope`].
+const CHECKSUM_LEN: usize = 32;
+
+/// A query together with its result and the proof of that result.
+///
+/// This is everything needed to verify the result except the commitments to the tables the query
+/// reads, which the verifier must obtain from a trusted source.
+#[derive(Serialize, Deserialize)]
+pub struct ProofEnvelope<CP: CommitmentEvaluationProof> {
+    /// The query that was proven
+    pub query: QueryExpr<CP::Commitment>,
+    /// The result of the query in intermediate form
+    pub result: ProvableQueryResult,
+    /// The proof that the result is valid
+    pub proof: QueryProof<CP>,
+}
+
+impl<CP: CommitmentEvaluationProof> ProofEnvelope<CP> {
+    /// Verify the result in this envelope against the commitments in `accessor`.
+    ///
+    /// Note: This does NOT transform the result! The postprocessing steps of the query are
+    /// available from [`QueryExpr::postprocessing`].
+    pub fn verify(
+        &self,
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> QueryResult<CP::Scalar> {
+        self.proof
+            .verify(self.query.proof_expr(), accessor, &self.result, setup)
+    }
+}
+
+impl<CP> ProofEnvelope<CP>
+where
+    CP: CommitmentEvaluationProof + Serialize,
+    CP::Commitment: Serialize,
+{
+    /// Encode this envelope as a versioned, checksummed `postcard` message.
+    ///
+    /// The encoding is the four bytes `PSQE`, then [`PROOF_ENVELOPE_ENCODING_VERSION`], then the
+    /// `postcard` serialization of the envelope, then the Keccak-256 hash of everything before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the envelope fails to serialize, which should never happen.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::from(ENVELOPE_MAGIC);
+        bytes.push(PROOF_ENVELOPE_ENCODING_VERSION);
+        let mut bytes =
+            postcard::to_extend(self, bytes).expect("envelopes should always serialize");
+        let checksum = checksum(&bytes);
+        bytes.extend_from_slice(&checksum);
+        bytes
+    }
+}
+
+impl<CP> ProofEnvelope<CP>
+where
+    CP: CommitmentEvaluationProof + DeserializeOwned,
+    CP::Commitment: DeserializeOwned,
+{
+    /// Decode an envelope produced by [`ProofEnvelope::to_bytes`].
+    ///
+    /// Envelopes written by a different encoding version, or whose checksum does not match their
+    /// contents, are rejected rather than misparsed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProofEnvelopeDecodeError> {
+        let body = bytes
+            .strip_prefix(&ENVELOPE_MAGIC)
+            .ok_or(ProofEnvelopeDecodeError::InvalidMagic)?;
+        let (&version, _) = body
+            .split_first()
+            .ok_or(ProofEnvelopeDecodeError::InvalidMagic)?;
+        if version != PROOF_ENVELOPE_ENCODING_VERSION {
+            return Err(ProofEnvelopeDecodeError::UnsupportedVersion {
+                version,
+                expected: PROOF_ENVELOPE_ENCODING_VERSION,
+            });
+        }
+        let checksummed_len = bytes
+            .len()
+            .checked_sub(CHECKSUM_LEN)
+            .filter(|&len| len > ENVELOPE_MAGIC.len())
+            .ok_or(ProofEnvelopeDecodeError::ChecksumMismatch)?;
+        let (checksummed, expected_checksum) = bytes.split_at(checksummed_len);
+        if checksum(checksummed) != expected_checksum {
+            return Err(ProofEnvelopeDecodeError::ChecksumMismatch);
+        }
+        let (envelope, remainder) =
+            postcard::take_from_bytes(&checksummed[ENVELOPE_MAGIC.len() + 1..])
+                .map_err(|error| ProofEnvelopeDecodeError::Deserialization { error })?;
+        if !remainder.is_empty() {
+            return Err(ProofEnvelopeDecodeError::TrailingBytes {
+                count: remainder.len(),
+            });
+        }
+        Ok(envelope)
+    }
+}
+
+/// The Keccak-256 hash of `bytes`.
+fn checksum(bytes: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let mut hasher = Keccak::v256();
+    hasher.update(bytes);
+    let mut checksum = [0; CHECKSUM_LEN];
+    hasher.finalize(&mut checksum);
+    checksum
+}
+
+/// Errors from decoding a [`ProofEnvelope`] with [`ProofEnvelope::from_bytes`].
+#[derive(Snafu, Debug)]
+pub enum ProofEnvelopeDecodeError {
+    /// The bytes do not start with the proof envelope header.
+    #[snafu(display("Bytes are not an encoded proof envelope"))]
+    InvalidMagic,
+    /// The envelope was encoded with a different version of the encoding.
+    #[snafu(display(
+        "Unsupported proof envelope encoding version {version}, expected {expected}"
+    ))]
+    UnsupportedVersion {
+        /// The version found in the header
+        version: u8,
+        /// The version this library reads and writes
+        expected: u8,
+    },
+    /// The checksum is missing or does not match the contents of the envelope.
+    #[snafu(display("Proof envelope checksum does not match its contents"))]
+    ChecksumMismatch,
+    /// The envelope body could not be deserialized.
+    #[snafu(display("Failed to deserialize proof envelope: {error}"))]
+    Deserialization {
+        /// The underlying `postcard` error
+        error: postcard::Error,
+    },
+    /// The envelope body was followed by unexpected bytes before the checksum.
+    #[snafu(display("Encoded proof envelope has {count} trailing bytes"))]
+    TrailingBytes {
+        /// The number of bytes after the envelope body
+        count: usize,
+    },
+}
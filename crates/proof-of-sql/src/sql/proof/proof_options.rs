@@ -0,0 +1,52 @@
+use super::{VerifyTrace, DEFAULT_MAX_SUMCHECK_VARIABLES};
+use crate::base::commitment::Commitment;
+
+/// Options for creating a proof with [`QueryProof::new_with_options`](super::QueryProof::new_with_options).
+///
+/// The default options produce the same proof as [`QueryProof::new`](super::QueryProof::new).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProveOptions<'a> {
+    /// Application-supplied data, such as a request nonce, that the proof is bound to.
+    ///
+    /// The context is appended to the transcript, so the proof only verifies given the same
+    /// [`VerifyOptions::context`].
+    pub context: &'a [u8],
+}
+
+/// Options for verifying a proof with
+/// [`QueryProof::verify_with_options`](super::QueryProof::verify_with_options).
+///
+/// The default options verify exactly like [`QueryProof::verify`](super::QueryProof::verify), so
+/// callers only need to set the fields they care about:
+///
+/// ```ignore
+/// let options = VerifyOptions {
+///     context: b"request-42",
+///     ..VerifyOptions::default()
+/// };
+/// ```
+#[derive(Debug)]
+pub struct VerifyOptions<'a, C: Commitment> {
+    /// The context the proof was created with, see [`ProveOptions::context`].
+    pub context: &'a [u8],
+    /// If given, the intermediate quantities of verification are recorded here.
+    ///
+    /// The trace is filled in even when verification fails. Without a trace, no extra work is done.
+    pub trace: Option<&'a mut VerifyTrace<C>>,
+    /// Proofs that require more sumcheck variables than this are rejected with
+    /// [`ProofError::ExceedsBudget`](crate::base::proof::ProofError::ExceedsBudget).
+    ///
+    /// The check is made before the verifier does any work that grows with the number of
+    /// variables, so a verifier serving untrusted requests can bound its memory and time.
+    pub max_sumcheck_variables: usize,
+}
+
+impl<C: Commitment> Default for VerifyOptions<'_, C> {
+    fn default() -> Self {
+        Self {
+            context: &[],
+            trace: None,
+            max_sumcheck_variables: DEFAULT_MAX_SUMCHECK_VARIABLES,
+        }
+    }
+}
@@ -28,9 +28,12 @@ pub trait ProofPlan<C: Commitment>: Debug + Send + Sync + ProverEvaluate<C::Scal
     /// The offset of the query, that is, how many rows to skip before starting to read the input table
     fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize;
 
-    /// Check if the input table is empty
+    /// The largest number of rows the query's result can have
+    fn max_output_length(&self, accessor: &dyn MetadataAccessor) -> usize;
+
+    /// Check if both the input table and the result are necessarily empty, so there is nothing to prove
     fn is_empty(&self, accessor: &dyn MetadataAccessor) -> bool {
-        self.get_length(accessor) == 0
+        self.get_length(accessor) == 0 && self.max_output_length(accessor) == 0
     }
 
     /// Form components needed to verify and proof store into `VerificationBuilder`
@@ -1,4 +1,7 @@
-use super::{decode_and_convert, decode_multiple_elements, ProvableResultColumn, QueryError};
+use super::{
+    decode_and_convert, decode_multiple_elements, ProvableResultColumn, ProvableResultElement,
+    QueryError,
+};
 use crate::base::{
     database::{Column, ColumnField, ColumnType, OwnedColumn, OwnedTable},
     polynomial::compute_evaluation_vector,
@@ -6,7 +9,38 @@ use crate::base::{
 };
 use alloc::{vec, vec::Vec};
 use num_traits::Zero;
+use proof_of_sql_parser::Identifier;
 use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+
+/// The ways in which the encoding of a [`ProvableQueryResult`] can be malformed
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum ResultError {
+    /// The result has a different number of columns than the query.
+    #[snafu(display("Result has {actual} columns but the query has {expected}"))]
+    ColumnCountMismatch {
+        /// The number of columns in the query
+        expected: usize,
+        /// The number of columns in the result
+        actual: usize,
+    },
+    /// An entry is cut off by the end of the data or has an invalid length prefix.
+    #[snafu(display("Row {row} of column {column} is malformed at byte {offset}"))]
+    MalformedEntry {
+        /// The column containing the entry
+        column: Identifier,
+        /// The row of the entry
+        row: usize,
+        /// The byte offset at which the entry starts
+        offset: usize,
+    },
+    /// There are bytes left over after the last column.
+    #[snafu(display("Result has {count} bytes after its last column"))]
+    TrailingBytes {
+        /// The number of bytes left over
+        count: usize,
+    },
+}
 
 /// An intermediate form of a query result that can be transformed
 /// to either the finalized query result form or a query error
@@ -81,6 +115,47 @@ impl ProvableQueryResult {
         }
     }
 
+    /// Check that the data is a well-formed encoding of `table_length` rows of the given columns.
+    ///
+    /// Only the framing of each entry is checked. An entry that decodes to a value outside its
+    /// column's type, or to invalid UTF-8, is reported by [`Self::to_owned_table`] instead.
+    ///
+    /// # Errors
+    /// Returns an error describing the first malformed part of the result.
+    pub fn validate(&self, column_result_fields: &[ColumnField]) -> Result<(), ResultError> {
+        if self.num_columns() != column_result_fields.len() {
+            return Err(ResultError::ColumnCountMismatch {
+                expected: column_result_fields.len(),
+                actual: self.num_columns(),
+            });
+        }
+        let mut offset = 0;
+        for field in column_result_fields {
+            // every entry takes at least one byte, so a bogus `table_length` stops at the end of the data
+            for row in 0..self.table_length() {
+                let data = &self.data[offset..];
+                let entry_length = match field.data_type() {
+                    ColumnType::VarChar => <&[u8]>::decode(data).ok().map(|(_, len)| len),
+                    _ => data
+                        .iter()
+                        .position(|byte| byte & 0b1000_0000 == 0)
+                        .map(|index| index + 1),
+                };
+                offset += entry_length.ok_or(ResultError::MalformedEntry {
+                    column: field.name(),
+                    row,
+                    offset,
+                })?;
+            }
+        }
+        if offset != self.data.len() {
+            return Err(ResultError::TrailingBytes {
+                count: self.data.len() - offset,
+            });
+        }
+        Ok(())
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     #[allow(
         clippy::missing_panics_doc,
@@ -137,10 +212,6 @@ impl ProvableQueryResult {
         Ok(res)
     }
 
-    #[allow(
-        clippy::missing_panics_doc,
-        reason = "Assertions ensure preconditions are met, eliminating the possibility of panic."
-    )]
     /// Convert the intermediate query result into a final query result
     ///
     /// The result is essentially an `OwnedTable` type.
@@ -213,8 +284,13 @@ impl ProvableQueryResult {
                 .collect::<Result<_, QueryError>>()?,
         )?;
 
-        assert_eq!(offset, self.data.len());
-        assert_eq!(owned_table.num_columns(), self.num_columns());
+        if offset != self.data.len() {
+            return Err(QueryError::MiscellaneousDecodingError);
+        }
+        // columns with the same name collapse into one
+        if owned_table.num_columns() != self.num_columns() {
+            return Err(QueryError::InvalidColumnCount);
+        }
 
         Ok(owned_table)
     }
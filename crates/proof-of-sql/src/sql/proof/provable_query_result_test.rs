@@ -1,4 +1,4 @@
-use super::{ProvableQueryResult, QueryError};
+use super::{ProvableQueryResult, QueryError, ResultError};
 use crate::base::{
     database::{Column, ColumnField, ColumnType},
     math::decimal::Precision,
@@ -253,6 +253,56 @@ fn evaluation_fails_if_data_is_missing() {
     ));
 }
 
+#[test]
+fn conversion_to_a_final_result_fails_if_extra_data_is_included() {
+    let cols: [Column<Curve25519Scalar>; 1] = [Column::BigInt(&[10, 12])];
+    let mut res = ProvableQueryResult::new(2, &cols);
+    res.data_mut().push(3u8);
+    let column_fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::BigInt)];
+    assert!(matches!(
+        res.to_owned_table::<Curve25519Scalar>(&column_fields),
+        Err(QueryError::MiscellaneousDecodingError)
+    ));
+}
+
+#[test]
+fn conversion_to_a_final_result_fails_if_two_columns_have_the_same_name() {
+    let cols: [Column<Curve25519Scalar>; 2] =
+        [Column::BigInt(&[10, 12]), Column::BigInt(&[11, 13])];
+    let res = ProvableQueryResult::new(2, &cols);
+    let column_fields = vec![
+        ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+        ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+    ];
+    assert!(matches!(
+        res.to_owned_table::<Curve25519Scalar>(&column_fields),
+        Err(QueryError::InvalidColumnCount)
+    ));
+}
+
+#[test]
+fn conversion_to_a_final_result_fails_if_the_table_length_exceeds_the_data() {
+    let res = ProvableQueryResult::new_from_raw_data(1, u64::MAX, vec![1, 2, 3]);
+    let column_fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::BigInt)];
+    assert!(matches!(
+        res.to_owned_table::<Curve25519Scalar>(&column_fields),
+        Err(QueryError::Overflow)
+    ));
+}
+
+#[test]
+fn conversion_to_a_final_result_fails_if_a_string_length_overflows() {
+    // varint encoding of `u64::MAX`
+    let mut data = vec![0xFF; 9];
+    data.push(0x01);
+    let res = ProvableQueryResult::new_from_raw_data(1, 1, data);
+    let column_fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::VarChar)];
+    assert!(matches!(
+        res.to_owned_table::<Curve25519Scalar>(&column_fields),
+        Err(QueryError::MiscellaneousDecodingError)
+    ));
+}
+
 #[test]
 fn we_can_convert_a_provable_result_to_a_final_result() {
     let cols: [Column<Curve25519Scalar>; 1] = [Column::BigInt(&[10, 12])];
@@ -390,3 +440,82 @@ fn we_can_convert_a_provable_result_to_a_final_result_with_mixed_data_types() {
     .unwrap();
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn we_can_validate_a_well_formed_provable_result() {
+    let cols: [Column<Curve25519Scalar>; 2] = [
+        Column::BigInt(&[10, -12]),
+        Column::VarChar((&["a", "bcd"], &[Curve25519Scalar::ZERO; 2])),
+    ];
+    let res = ProvableQueryResult::new(2, &cols);
+    let column_fields = vec![
+        ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+        ColumnField::new("b".parse().unwrap(), ColumnType::VarChar),
+    ];
+    assert_eq!(res.validate(&column_fields), Ok(()));
+    assert_eq!(
+        res.validate(&column_fields[..1]),
+        Err(ResultError::ColumnCountMismatch {
+            expected: 1,
+            actual: 2
+        })
+    );
+}
+
+#[test]
+fn validation_reports_where_a_provable_result_is_malformed() {
+    let column_fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::BigInt)];
+    let res = ProvableQueryResult::new_from_raw_data(1, 3, vec![1, 0b1000_0010]);
+    assert_eq!(
+        res.validate(&column_fields),
+        Err(ResultError::MalformedEntry {
+            column: "a".parse().unwrap(),
+            row: 1,
+            offset: 1
+        })
+    );
+
+    let res = ProvableQueryResult::new_from_raw_data(1, u64::MAX, vec![1, 2, 3]);
+    assert_eq!(
+        res.validate(&column_fields),
+        Err(ResultError::MalformedEntry {
+            column: "a".parse().unwrap(),
+            row: 3,
+            offset: 3
+        })
+    );
+
+    let mut res = ProvableQueryResult::new_from_raw_data(1, 1, vec![4]);
+    res.data_mut().push(3u8);
+    assert_eq!(
+        res.validate(&column_fields),
+        Err(ResultError::TrailingBytes { count: 1 })
+    );
+
+    // varint encoding of `u64::MAX` as a string length
+    let mut data = vec![0xFF; 9];
+    data.push(0x01);
+    let res = ProvableQueryResult::new_from_raw_data(1, 1, data);
+    let column_fields = vec![ColumnField::new("b".parse().unwrap(), ColumnType::VarChar)];
+    assert_eq!(
+        res.validate(&column_fields),
+        Err(ResultError::MalformedEntry {
+            column: "b".parse().unwrap(),
+            row: 0,
+            offset: 0
+        })
+    );
+}
+
+#[test]
+fn validation_leaves_out_of_range_values_to_conversion() {
+    let binding = [i64::from(i32::MAX) + 1_i64, 12];
+    let cols: [Column<Curve25519Scalar>; 1] = [Column::BigInt(&binding)];
+    let res = ProvableQueryResult::new(2, &cols);
+    let column_fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::Int)];
+    assert_eq!(res.validate(&column_fields), Ok(()));
+    assert!(matches!(
+        res.to_owned_table::<Curve25519Scalar>(&column_fields),
+        Err(QueryError::Overflow)
+    ));
+}
@@ -1,24 +1,34 @@
 use super::{
-    CountBuilder, FinalRoundBuilder, ProofCounts, ProofPlan, ProvableQueryResult, QueryResult,
-    SumcheckMleEvaluations, SumcheckRandomScalars, VerificationBuilder,
+    CountBuilder, FinalRoundBuilder, ProofCounts, ProofPlan, ProvableQueryResult, ProveOptions,
+    QueryError, QueryResult, SumcheckMleEvaluations, SumcheckRandomScalars, VerificationBuilder,
+    VerifyOptions,
 };
 use crate::{
     base::{
         bit::BitDistribution,
         commitment::{Commitment, CommitmentEvaluationProof},
-        database::{Column, CommitmentAccessor, DataAccessor},
+        database::{Column, CommitmentAccessor, DataAccessor, OwnedTable},
         math::log2_up,
         polynomial::{compute_evaluation_vector, CompositePolynomialInfo},
         proof::{Keccak256Transcript, ProofError, Transcript},
+        scalar::ScalarExt,
     },
     proof_primitive::sumcheck::SumcheckProof,
     sql::proof::{FirstRoundBuilder, QueryData},
 };
 use alloc::{vec, vec::Vec};
 use bumpalo::Bump;
-use core::cmp;
+use core::{cmp, fmt};
 use num_traits::Zero;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use snafu::Snafu;
+use tracing::{span, Level};
+
+/// The largest number of sumcheck variables that [`QueryProof::verify`] accepts.
+///
+/// This admits tables of up to `2^32` rows. Set [`VerifyOptions::max_sumcheck_variables`] to
+/// verify against a different cap.
+pub const DEFAULT_MAX_SUMCHECK_VARIABLES: usize = 32;
 
 /// The proof for a query.
 ///
@@ -41,31 +51,66 @@ pub struct QueryProof<CP: CommitmentEvaluationProof> {
 
 impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
     /// Create a new `QueryProof`.
-    #[tracing::instrument(name = "QueryProof::new", level = "debug", skip_all)]
+    ///
+    /// Each proving phase runs inside its own `debug` span (`result_evaluate`,
+    /// `final_round_evaluate`, `commit_intermediate_mles`, `sumcheck`,
+    /// `evaluate_pcs_proof_mles` and `evaluation_proof`), so a `tracing` subscriber
+    /// can record where proving time goes.
     pub fn new(
         expr: &(impl ProofPlan<CP::Commitment> + Serialize),
         accessor: &impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
+    ) -> (Self, ProvableQueryResult) {
+        Self::new_with_options::<Keccak256Transcript>(
+            expr,
+            accessor,
+            setup,
+            ProveOptions::default(),
+        )
+    }
+
+    /// Create a new `QueryProof` with the given `options`, deriving every challenge from a
+    /// transcript of type `T`.
+    ///
+    /// A transcript other than [`Keccak256Transcript`] allows one that is cheap to verify inside
+    /// another proof system. The proof only verifies with [`QueryProof::verify_with_options`]
+    /// using the same `T` and context.
+    #[tracing::instrument(name = "QueryProof::new", level = "debug", skip_all)]
+    pub fn new_with_options<T: Transcript>(
+        expr: &(impl ProofPlan<CP::Commitment> + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        options: ProveOptions<'_>,
     ) -> (Self, ProvableQueryResult) {
         let table_length = expr.get_length(accessor);
-        let num_sumcheck_variables = cmp::max(log2_up(table_length), 1);
         let generator_offset = expr.get_offset(accessor);
-        assert!(num_sumcheck_variables > 0);
 
         let alloc = Bump::new();
 
         // Evaluate query result
+        let span = span!(Level::DEBUG, "result_evaluate").entered();
         let result_cols = expr.result_evaluate(table_length, &alloc, accessor);
         let output_length = result_cols.first().map_or(0, Column::len);
         let provable_result = ProvableQueryResult::new(output_length as u64, &result_cols);
+        span.exit();
+
+        // An aggregate without `GROUP BY` has a row even when the table has none, so the
+        // constraints range over whichever is longer.
+        let range_length = cmp::max(table_length, output_length);
+        let num_sumcheck_variables = cmp::max(log2_up(range_length), 1);
 
         // Prover First Round
         let mut first_round_builder = FirstRoundBuilder::new();
         expr.first_round_evaluate(&mut first_round_builder);
 
         // construct a transcript for the proof
-        let mut transcript: Keccak256Transcript =
-            make_transcript(expr, &provable_result, table_length, generator_offset);
+        let mut transcript: T = make_transcript(
+            expr,
+            &provable_result,
+            table_length,
+            generator_offset,
+            options.context,
+        );
 
         // These are the challenges that will be consumed by the proof
         // Specifically, these are the challenges that the verifier sends to
@@ -77,20 +122,28 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
                 .take(first_round_builder.num_post_result_challenges())
                 .collect();
 
-        let mut builder =
-            FinalRoundBuilder::new(table_length, num_sumcheck_variables, post_result_challenges);
+        let mut builder = FinalRoundBuilder::new(
+            table_length,
+            range_length,
+            num_sumcheck_variables,
+            post_result_challenges,
+        );
+        let span = span!(Level::DEBUG, "final_round_evaluate").entered();
         expr.final_round_evaluate(&mut builder, &alloc, accessor);
+        span.exit();
 
         let num_sumcheck_variables = builder.num_sumcheck_variables();
-        let table_length = builder.table_length();
 
         // commit to any intermediate MLEs
+        let span = span!(Level::DEBUG, "commit_intermediate_mles").entered();
         let commitments = builder.commit_intermediate_mles(generator_offset, setup);
+        span.exit();
 
         // add the commitments and bit distributions to the proof
         extend_transcript(&mut transcript, &commitments, builder.bit_distributions());
 
         // construct the sumcheck polynomial
+        let span = span!(Level::DEBUG, "sumcheck").entered();
         let num_random_scalars = num_sumcheck_variables + builder.num_sumcheck_subpolynomials();
         let random_scalars: Vec<_> =
             core::iter::repeat_with(|| transcript.scalar_challenge_as_be())
@@ -98,18 +151,21 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
                 .collect();
         let poly = builder.make_sumcheck_polynomial(&SumcheckRandomScalars::new(
             &random_scalars,
-            table_length,
+            range_length,
             num_sumcheck_variables,
         ));
 
         // create the sumcheck proof -- this is the main part of proving a query
         let mut evaluation_point = vec![Zero::zero(); poly.num_variables];
         let sumcheck_proof = SumcheckProof::create(&mut transcript, &mut evaluation_point, &poly);
+        span.exit();
 
         // evaluate the MLEs used in sumcheck except for the result columns
-        let mut evaluation_vec = vec![Zero::zero(); table_length];
+        let span = span!(Level::DEBUG, "evaluate_pcs_proof_mles").entered();
+        let mut evaluation_vec = vec![Zero::zero(); range_length];
         compute_evaluation_vector(&mut evaluation_vec, &evaluation_point);
         let pcs_proof_evaluations = builder.evaluate_pcs_proof_mles(&evaluation_vec);
+        span.exit();
 
         // commit to the MLE evaluations
         transcript.extend_canonical_serialize_as_le(&pcs_proof_evaluations);
@@ -123,6 +179,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         let folded_mle = builder.fold_pcs_proof_mles(&random_scalars);
 
         // finally, form the inner product proof of the MLEs' evaluations
+        let span = span!(Level::DEBUG, "evaluation_proof").entered();
         let evaluation_proof = CP::new(
             &mut transcript,
             &folded_mle,
@@ -130,6 +187,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             generator_offset as u64,
             setup,
         );
+        span.exit();
 
         let proof = Self {
             bit_distributions: builder.bit_distributions().to_vec(),
@@ -141,7 +199,6 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         (proof, provable_result)
     }
 
-    #[tracing::instrument(name = "QueryProof::verify", level = "debug", skip_all, err)]
     /// Verify a `QueryProof`. Note: This does NOT transform the result!
     pub fn verify(
         &self,
@@ -150,11 +207,97 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         result: &ProvableQueryResult,
         setup: &CP::VerifierPublicSetup<'_>,
     ) -> QueryResult<CP::Scalar> {
+        self.verify_with_options::<Keccak256Transcript>(
+            expr,
+            accessor,
+            result,
+            setup,
+            VerifyOptions::default(),
+        )
+    }
+
+    /// Verify a `QueryProof` like [`QueryProof::verify`], but return only the result columns at
+    /// the indexes in `columns`, in that order.
+    ///
+    /// Every column of the result is still verified, so this is exactly as sound as
+    /// [`QueryProof::verify`]. The requested columns are moved out of the verified result rather
+    /// than copied, and the rest are dropped.
+    pub fn verify_projected(
+        &self,
+        expr: &(impl ProofPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        result: &ProvableQueryResult,
+        setup: &CP::VerifierPublicSetup<'_>,
+        columns: &[usize],
+    ) -> QueryResult<CP::Scalar> {
+        let QueryData {
+            table,
+            verification_hash,
+        } = self.verify(expr, accessor, result, setup)?;
+        let mut slots: Vec<_> = table.into_inner().into_iter().map(Some).collect();
+        let column_count = slots.len();
+        let selected = columns
+            .iter()
+            .map(|&index| {
+                slots
+                    .get_mut(index)
+                    .ok_or(QueryError::ColumnIndexOutOfRange {
+                        index,
+                        column_count,
+                    })?
+                    .take()
+                    .ok_or(QueryError::DuplicateColumnIndex { index })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(QueryData {
+            table: OwnedTable::try_from_iter(selected)?,
+            verification_hash,
+        })
+    }
+
+    /// Verify a `QueryProof` created with [`QueryProof::new_with_options`] using the same
+    /// transcript type `T`, as configured by `options`.
+    ///
+    /// Note: This does NOT transform the result!
+    #[tracing::instrument(name = "QueryProof::verify", level = "debug", skip_all, err)]
+    #[allow(clippy::too_many_lines)]
+    pub fn verify_with_options<T: Transcript>(
+        &self,
+        expr: &(impl ProofPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        result: &ProvableQueryResult,
+        setup: &CP::VerifierPublicSetup<'_>,
+        options: VerifyOptions<'_, CP::Commitment>,
+    ) -> QueryResult<CP::Scalar> {
+        let VerifyOptions {
+            context,
+            mut trace,
+            max_sumcheck_variables,
+        } = options;
+        // an accessor without a commitment for some table fails here rather than panicking below
+        for table_ref in expr.get_table_references() {
+            accessor.try_get_length(table_ref)?;
+        }
         let input_length = expr.get_length(accessor);
         let output_length = result.table_length();
         let generator_offset = expr.get_offset(accessor);
-        let num_sumcheck_variables = cmp::max(log2_up(input_length), 1);
-        assert!(num_sumcheck_variables > 0);
+
+        // bound the work done on the result by the number of rows the plan can produce
+        let max_output_length = expr.max_output_length(accessor);
+        if output_length > max_output_length {
+            Err(ProofError::ResultTooLarge {
+                max: max_output_length,
+                actual: output_length,
+            })?;
+        }
+        let range_length = cmp::max(input_length, output_length);
+        let num_sumcheck_variables = cmp::max(log2_up(range_length), 1);
+        if num_sumcheck_variables > max_sumcheck_variables {
+            Err(ProofError::ExceedsBudget {
+                max: max_sumcheck_variables,
+                actual: num_sumcheck_variables,
+            })?;
+        }
 
         // validate bit decompositions
         for dist in &self.bit_distributions {
@@ -173,15 +316,21 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         }?;
 
         // verify sizes
-        if !self.validate_sizes(&counts) {
-            Err(ProofError::VerificationError {
-                error: "invalid proof size",
+        self.validate_sizes(&counts)?;
+
+        let column_result_fields = expr.get_column_result_fields();
+        if result.num_columns() != column_result_fields.len() {
+            Err(ProofError::ColumnCountMismatch {
+                expected: column_result_fields.len(),
+                actual: result.num_columns(),
             })?;
         }
 
+        result.validate(&column_result_fields)?;
+
         // construct a transcript for the proof
-        let mut transcript: Keccak256Transcript =
-            make_transcript(expr, result, input_length, generator_offset);
+        let mut transcript: T =
+            make_transcript(expr, result, input_length, generator_offset, context);
 
         // These are the challenges that will be consumed by the proof
         // Specifically, these are the challenges that the verifier sends to
@@ -203,7 +352,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
                 .take(num_random_scalars)
                 .collect();
         let sumcheck_random_scalars =
-            SumcheckRandomScalars::new(&random_scalars, input_length, num_sumcheck_variables);
+            SumcheckRandomScalars::new(&random_scalars, range_length, num_sumcheck_variables);
 
         // verify sumcheck up to the evaluation check
         let poly_info = CompositePolynomialInfo {
@@ -217,6 +366,12 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             poly_info,
             &Zero::zero(),
         )?;
+        if let Some(trace) = trace.as_deref_mut() {
+            trace
+                .evaluation_point
+                .clone_from(&subclaim.evaluation_point);
+            trace.expected_sumcheck_evaluation = Some(subclaim.expected_evaluation);
+        }
 
         // commit to mle evaluations
         transcript.extend_canonical_serialize_as_le(&self.pcs_proof_evaluations);
@@ -228,7 +383,12 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
                 .take(self.pcs_proof_evaluations.len())
                 .collect();
 
-        let column_result_fields = expr.get_column_result_fields();
+        // compute the evaluation of the result MLEs
+        let result_evaluations = result.evaluate(
+            &subclaim.evaluation_point,
+            output_length,
+            &column_result_fields[..],
+        )?;
 
         // pass over the provable AST to fill in the verification builder
         let sumcheck_evaluations = SumcheckMleEvaluations::new(
@@ -237,6 +397,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             &subclaim.evaluation_point,
             &sumcheck_random_scalars,
             &self.pcs_proof_evaluations,
+            &result_evaluations,
         );
         let mut builder = VerificationBuilder::new(
             generator_offset,
@@ -250,21 +411,34 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         let owned_table_result = result.to_owned_table(&column_result_fields[..])?;
         let verifier_evaluations =
             expr.verifier_evaluate(&mut builder, accessor, Some(&owned_table_result))?;
-        // compute the evaluation of the result MLEs
-        let result_evaluations = result.evaluate(
-            &subclaim.evaluation_point,
-            output_length,
-            &column_result_fields[..],
-        )?;
+        if let Some(trace) = trace {
+            trace
+                .verifier_result_evaluations
+                .clone_from(&verifier_evaluations);
+            trace.result_evaluations.clone_from(&result_evaluations);
+            trace.sumcheck_evaluation = Some(builder.sumcheck_evaluation());
+            trace.folded_pcs_proof_commitment = Some(fold_commitments(
+                builder.pcs_proof_commitments(),
+                builder.inner_product_multipliers(),
+            ));
+            trace.folded_pcs_proof_evaluation = Some(builder.folded_pcs_proof_evaluation());
+        }
         // check the evaluation of the result MLEs
-        if verifier_evaluations != result_evaluations {
+        if !bool::from(CP::Scalar::ct_eq_slice(
+            &verifier_evaluations,
+            &result_evaluations,
+        )) {
             Err(ProofError::VerificationError {
                 error: "result evaluation check failed",
             })?;
         }
 
         // perform the evaluation check of the sumcheck polynomial
-        if builder.sumcheck_evaluation() != subclaim.expected_evaluation {
+        if !bool::from(
+            builder
+                .sumcheck_evaluation()
+                .ct_eq(&subclaim.expected_evaluation),
+        ) {
             Err(ProofError::VerificationError {
                 error: "sumcheck evaluation check failed",
             })?;
@@ -280,7 +454,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
                 &product,
                 &subclaim.evaluation_point,
                 generator_offset as u64,
-                input_length,
+                range_length,
                 setup,
             )
             .map_err(|_e| ProofError::VerificationError {
@@ -294,12 +468,383 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         })
     }
 
-    fn validate_sizes(&self, counts: &ProofCounts) -> bool {
-        self.commitments.len() == counts.intermediate_mles
-            && self.pcs_proof_evaluations.len() == counts.intermediate_mles + counts.anchored_mles
+    /// The number of each kind of component in this proof.
+    ///
+    /// This is enough to check that a proof has the shape a query expects without
+    /// printing its contents.
+    #[must_use]
+    pub fn summary(&self) -> QueryProofSummary {
+        QueryProofSummary {
+            bit_distributions: self.bit_distributions.len(),
+            commitments: self.commitments.len(),
+            sumcheck_coefficients: self.sumcheck_proof.num_coefficients(),
+            pcs_proof_evaluations: self.pcs_proof_evaluations.len(),
+        }
+    }
+
+    fn validate_sizes(&self, counts: &ProofCounts) -> Result<(), ProofError> {
+        if self.commitments.len() != counts.intermediate_mles {
+            return Err(ProofError::CommitmentCountMismatch {
+                expected: counts.intermediate_mles,
+                actual: self.commitments.len(),
+            });
+        }
+        let expected_evaluations = counts.intermediate_mles + counts.anchored_mles;
+        if self.pcs_proof_evaluations.len() != expected_evaluations {
+            return Err(ProofError::MleEvaluationCountMismatch {
+                expected: expected_evaluations,
+                actual: self.pcs_proof_evaluations.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<CP: CommitmentEvaluationProof + Serialize> QueryProof<CP> {
+    /// The exact number of bytes in the `postcard` serialization of this proof.
+    ///
+    /// The size is computed without allocating a buffer for the serialized bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a component of the proof fails to serialize, which should never happen.
+    #[must_use]
+    pub fn serialized_size(&self) -> usize {
+        self.serialized_size_breakdown().total()
+    }
+
+    /// The number of bytes each component of this proof takes up in its `postcard` serialization.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a component of the proof fails to serialize, which should never happen.
+    #[must_use]
+    pub fn serialized_size_breakdown(&self) -> QueryProofSize {
+        QueryProofSize {
+            bit_distributions: size_of(&self.bit_distributions),
+            commitments: size_of(&self.commitments),
+            sumcheck_proof: size_of(&self.sumcheck_proof),
+            pcs_proof_evaluations: size_of(&self.pcs_proof_evaluations),
+            evaluation_proof: size_of(&self.evaluation_proof),
+        }
+    }
+}
+
+/// The number of each kind of component in a [`QueryProof`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryProofSummary {
+    /// Number of bit distributions
+    pub bit_distributions: usize,
+    /// Number of intermediate commitments
+    pub commitments: usize,
+    /// Number of coefficients in the sumcheck proof
+    ///
+    /// Each sumcheck round contributes one coefficient more than the degree of the
+    /// sumcheck polynomial, which depends on the query.
+    pub sumcheck_coefficients: usize,
+    /// Number of MLE evaluations
+    pub pcs_proof_evaluations: usize,
+}
+
+// Prints the component counts and a short prefix of each commitment rather than
+// the full contents of the proof.
+impl<CP: CommitmentEvaluationProof> fmt::Debug for QueryProof<CP>
+where
+    CP::Commitment: Serialize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let summary = self.summary();
+        let commitments: Vec<_> = self.commitments.iter().map(TruncatedHex).collect();
+        f.debug_struct("QueryProof")
+            .field("bit_distributions", &summary.bit_distributions)
+            .field("commitments", &commitments)
+            .field("sumcheck_coefficients", &summary.sumcheck_coefficients)
+            .field("pcs_proof_evaluations", &summary.pcs_proof_evaluations)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Formats a value as the first bytes of its `postcard` serialization in hex.
+struct TruncatedHex<'a, T>(&'a T);
+
+impl<T: Serialize> fmt::Debug for TruncatedHex<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const PREFIX_LEN: usize = 8;
+        let bytes = postcard::to_allocvec(self.0).map_err(|_| fmt::Error)?;
+        bytes
+            .iter()
+            .take(PREFIX_LEN)
+            .try_for_each(|byte| write!(f, "{byte:02x}"))?;
+        if bytes.len() > PREFIX_LEN {
+            write!(f, "..")?;
+        }
+        Ok(())
     }
 }
 
+/// The bytes every encoded [`QueryProof`] starts with.
+const ENCODING_MAGIC: [u8; 4] = *b"PSQP";
+
+/// The version of the [`QueryProof`] encoding written by [`QueryProof::to_bytes`].
+///
+/// This must be bumped whenever the serialized layout of a proof changes, and whenever the
+/// components a verifier expects in a proof change, so that older proofs are rejected on decode
+/// rather than failing verification.
+///
+/// Version 2 stopped committing to the result columns of projections and filters.
+pub const QUERY_PROOF_ENCODING_VERSION: u8 = 2;
+
+impl<CP: CommitmentEvaluationProof + Serialize> QueryProof<CP> {
+    /// Encode this proof as a versioned `postcard` message.
+    ///
+    /// The encoding is the four bytes `PSQP`, then [`QUERY_PROOF_ENCODING_VERSION`],
+    /// then the `postcard` serialization of the proof.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the proof fails to serialize, which should never happen.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(ENCODING_MAGIC.len() + 1 + self.serialized_size());
+        bytes.extend_from_slice(&ENCODING_MAGIC);
+        bytes.push(QUERY_PROOF_ENCODING_VERSION);
+        postcard::to_extend(self, bytes).expect("proofs should always serialize")
+    }
+}
+
+impl<CP: CommitmentEvaluationProof + DeserializeOwned> QueryProof<CP> {
+    /// Decode a proof produced by [`QueryProof::to_bytes`].
+    ///
+    /// Proofs written by a different encoding version are rejected rather than misparsed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, QueryProofDecodeError> {
+        let body = bytes
+            .strip_prefix(&ENCODING_MAGIC)
+            .ok_or(QueryProofDecodeError::InvalidMagic)?;
+        let (&version, body) = body
+            .split_first()
+            .ok_or(QueryProofDecodeError::InvalidMagic)?;
+        if version != QUERY_PROOF_ENCODING_VERSION {
+            return Err(QueryProofDecodeError::UnsupportedVersion {
+                version,
+                expected: QUERY_PROOF_ENCODING_VERSION,
+            });
+        }
+        let (proof, remainder) = postcard::take_from_bytes(body)
+            .map_err(|error| QueryProofDecodeError::Deserialization { error })?;
+        if !remainder.is_empty() {
+            return Err(QueryProofDecodeError::TrailingBytes {
+                count: remainder.len(),
+            });
+        }
+        Ok(proof)
+    }
+}
+
+/// The largest number of each kind of component that [`QueryProof::read_from`] accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryProofReadLimits {
+    /// Maximum number of bit distributions
+    pub bit_distributions: usize,
+    /// Maximum number of intermediate commitments
+    pub commitments: usize,
+    /// Maximum number of coefficients in the sumcheck proof
+    pub sumcheck_coefficients: usize,
+    /// Maximum number of MLE evaluations
+    pub pcs_proof_evaluations: usize,
+}
+
+/// The default limits on the number of each kind of component that
+/// [`QueryProof::read_from`] accepts.
+///
+/// These are far above what any supported query needs.
+pub const DEFAULT_QUERY_PROOF_READ_LIMITS: QueryProofReadLimits = QueryProofReadLimits {
+    bit_distributions: 1 << 16,
+    commitments: 1 << 16,
+    sumcheck_coefficients: 1 << 16,
+    pcs_proof_evaluations: 1 << 16,
+};
+
+#[cfg(feature = "std")]
+impl<CP: CommitmentEvaluationProof + DeserializeOwned> QueryProof<CP> {
+    /// Decode a proof produced by [`QueryProof::to_bytes`] from a stream.
+    ///
+    /// The proof is read one component at a time, and each list of components is rejected
+    /// before it is read if it claims more entries than `limits` allows. A truncated or malicious
+    /// stream therefore fails without buffering the whole proof or allocating more than the
+    /// limits permit. The reader is left positioned just after the proof.
+    pub fn read_from(
+        mut reader: impl std::io::Read,
+        limits: &QueryProofReadLimits,
+    ) -> Result<Self, QueryProofDecodeError> {
+        let mut header = [0; ENCODING_MAGIC.len() + 1];
+        reader
+            .read_exact(&mut header)
+            .map_err(|source| QueryProofDecodeError::Io { source })?;
+        let [magic @ .., version] = header;
+        if magic != ENCODING_MAGIC {
+            return Err(QueryProofDecodeError::InvalidMagic);
+        }
+        if version != QUERY_PROOF_ENCODING_VERSION {
+            return Err(QueryProofDecodeError::UnsupportedVersion {
+                version,
+                expected: QUERY_PROOF_ENCODING_VERSION,
+            });
+        }
+        let mut scratch = [0; 64];
+        let mut deserializer = postcard::Deserializer::from_flavor(
+            postcard::de_flavors::io::io::IOReader::new(reader, &mut scratch),
+        );
+        let bit_distributions = read_list(
+            &mut deserializer,
+            "bit distributions",
+            limits.bit_distributions,
+        )?;
+        let commitments = read_list(&mut deserializer, "commitments", limits.commitments)?;
+        let sumcheck_proof = SumcheckProof::from_coefficients(read_list(
+            &mut deserializer,
+            "sumcheck coefficients",
+            limits.sumcheck_coefficients,
+        )?);
+        let pcs_proof_evaluations = read_list(
+            &mut deserializer,
+            "MLE evaluations",
+            limits.pcs_proof_evaluations,
+        )?;
+        let evaluation_proof = CP::deserialize(&mut deserializer)
+            .map_err(|error| QueryProofDecodeError::Deserialization { error })?;
+        Ok(Self {
+            bit_distributions,
+            commitments,
+            sumcheck_proof,
+            pcs_proof_evaluations,
+            evaluation_proof,
+        })
+    }
+}
+
+/// Read a `postcard` encoded list of at most `max` entries.
+#[cfg(feature = "std")]
+fn read_list<'de, T: Deserialize<'de>>(
+    deserializer: &mut postcard::Deserializer<'de, impl postcard::de_flavors::Flavor<'de>>,
+    component: &'static str,
+    max: usize,
+) -> Result<Vec<T>, QueryProofDecodeError> {
+    let len = usize::deserialize(&mut *deserializer)
+        .map_err(|error| QueryProofDecodeError::Deserialization { error })?;
+    if len > max {
+        return Err(QueryProofDecodeError::TooManyComponents {
+            component,
+            max,
+            actual: len,
+        });
+    }
+    (0..len)
+        .map(|_| {
+            T::deserialize(&mut *deserializer)
+                .map_err(|error| QueryProofDecodeError::Deserialization { error })
+        })
+        .collect()
+}
+
+/// Errors from decoding a [`QueryProof`] with [`QueryProof::from_bytes`] or
+/// [`QueryProof::read_from`].
+#[derive(Snafu, Debug)]
+pub enum QueryProofDecodeError {
+    /// The bytes do not start with the query proof header.
+    #[snafu(display("Bytes are not an encoded query proof"))]
+    InvalidMagic,
+    /// The proof was encoded with a different version of the encoding.
+    #[snafu(display("Unsupported query proof encoding version {version}, expected {expected}"))]
+    UnsupportedVersion {
+        /// The version found in the header
+        version: u8,
+        /// The version this library reads and writes
+        expected: u8,
+    },
+    /// The proof body could not be deserialized.
+    #[snafu(display("Failed to deserialize query proof: {error}"))]
+    Deserialization {
+        /// The underlying `postcard` error
+        error: postcard::Error,
+    },
+    /// The proof has more of some kind of component than the reader allows.
+    #[snafu(display(
+        "Encoded query proof has {actual} {component}, but at most {max} are allowed"
+    ))]
+    TooManyComponents {
+        /// The kind of component
+        component: &'static str,
+        /// The largest number of components allowed
+        max: usize,
+        /// The number of components in the proof
+        actual: usize,
+    },
+    /// The proof body was followed by unexpected bytes.
+    #[snafu(display("Encoded query proof has {count} trailing bytes"))]
+    TrailingBytes {
+        /// The number of bytes after the proof
+        count: usize,
+    },
+    /// The stream could not be read, for example because it ended before the proof header.
+    #[cfg(feature = "std")]
+    #[snafu(display("Failed to read query proof: {source}"))]
+    Io {
+        /// The underlying I/O error
+        source: std::io::Error,
+    },
+}
+
+/// The number of bytes in the `postcard` serialization of `value`.
+///
+/// # Panics
+///
+/// Panics if `value` fails to serialize.
+fn size_of(value: &impl Serialize) -> usize {
+    postcard::serialize_with_flavor(value, postcard::ser_flavors::Size::default())
+        .expect("proof components should always serialize")
+}
+
+/// The serialized size in bytes of each component of a [`QueryProof`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryProofSize {
+    /// Size of the bit distributions
+    pub bit_distributions: usize,
+    /// Size of the intermediate commitments
+    pub commitments: usize,
+    /// Size of the sumcheck proof
+    pub sumcheck_proof: usize,
+    /// Size of the MLE evaluations
+    pub pcs_proof_evaluations: usize,
+    /// Size of the evaluation proof
+    pub evaluation_proof: usize,
+}
+
+impl QueryProofSize {
+    /// The total serialized size of the proof.
+    ///
+    /// `postcard` serializes a struct as the concatenation of its fields,
+    /// so this is the sum of the component sizes.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.bit_distributions
+            + self.commitments
+            + self.sumcheck_proof
+            + self.pcs_proof_evaluations
+            + self.evaluation_proof
+    }
+}
+
+/// Form the random linear combination of `commitments` with the given `multipliers`.
+fn fold_commitments<C: Commitment>(commitments: &[C], multipliers: &[C::Scalar]) -> C {
+    commitments.iter().zip(multipliers).fold(
+        C::default(),
+        |mut folded, (commitment, multiplier)| {
+            folded += *multiplier * commitment;
+            folded
+        },
+    )
+}
+
 /// Creates a transcript using the Merlin library.
 ///
 /// This function is used to produce a transcript for a proof expression
@@ -319,6 +864,9 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
 ///
 /// * `generator_offset` - The offset of the generator used in the proof, as a `usize`.
 ///
+/// * `context` - Application-supplied bytes the proof is bound to. Nothing is appended
+///   when it is empty, so proofs without a context are unchanged.
+///
 /// # Returns
 /// This function returns a `merlin::Transcript`. The transcript is a record
 /// of all the operations and data involved in creating a proof.
@@ -328,12 +876,16 @@ fn make_transcript<C: Commitment, T: Transcript>(
     result: &ProvableQueryResult,
     table_length: usize,
     generator_offset: usize,
+    context: &[u8],
 ) -> T {
     let mut transcript = T::new();
     transcript.extend_serialize_as_le(result);
     transcript.extend_serialize_as_le(expr);
     transcript.extend_serialize_as_le(&table_length);
     transcript.extend_serialize_as_le(&generator_offset);
+    if !context.is_empty() {
+        transcript.extend_serialize_as_le(context);
+    }
     transcript
 }
 
@@ -0,0 +1,116 @@
+//! Targeted corruptions of a [`QueryProof`], for testing that the verifier rejects malformed proofs.
+//!
+//! Each function takes a proof, applies one corruption and returns it, and documents the check in
+//! [`QueryProof::verify`] that it is meant to trip.
+use super::QueryProof;
+use crate::base::{commitment::CommitmentEvaluationProof, scalar::Scalar};
+
+/// Drops the last intermediate commitment.
+///
+/// Meant to trip the size validation, which fails with `ProofError::CommitmentCountMismatch`.
+///
+/// # Panics
+///
+/// Panics if the proof has no commitments.
+#[must_use]
+pub fn remove_commitment<CP: CommitmentEvaluationProof>(
+    mut proof: QueryProof<CP>,
+) -> QueryProof<CP> {
+    proof
+        .commitments
+        .pop()
+        .expect("the proof has no commitments");
+    proof
+}
+
+/// Doubles the intermediate commitment at `index`.
+///
+/// Meant to trip the sumcheck round check, which fails with "round evaluation does not match
+/// claimed sum". The commitments are absorbed into the transcript, so changing one changes the
+/// sumcheck challenges and the prover's round polynomials no longer agree with them. The
+/// commitment at `index` must not be the identity, since doubling would leave it unchanged.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds.
+#[must_use]
+pub fn perturb_commitment<CP: CommitmentEvaluationProof>(
+    mut proof: QueryProof<CP>,
+    index: usize,
+) -> QueryProof<CP> {
+    let commitment = proof.commitments[index].clone();
+    proof.commitments[index] += commitment;
+    proof
+}
+
+/// Drops the last sumcheck round coefficient.
+///
+/// Meant to trip the sumcheck size check, which fails with "invalid proof size".
+///
+/// # Panics
+///
+/// Panics if the sumcheck proof has no coefficients.
+#[must_use]
+pub fn truncate_sumcheck_proof<CP: CommitmentEvaluationProof>(
+    mut proof: QueryProof<CP>,
+) -> QueryProof<CP> {
+    proof
+        .sumcheck_proof
+        .coefficients_mut()
+        .pop()
+        .expect("the sumcheck proof has no coefficients");
+    proof
+}
+
+/// Adds one to the sumcheck round coefficient at `index`.
+///
+/// Meant to trip the sumcheck round check, which fails with "round evaluation does not match
+/// claimed sum".
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds.
+#[must_use]
+pub fn perturb_sumcheck_proof<CP: CommitmentEvaluationProof>(
+    mut proof: QueryProof<CP>,
+    index: usize,
+) -> QueryProof<CP> {
+    proof.sumcheck_proof.coefficients_mut()[index] += CP::Scalar::ONE;
+    proof
+}
+
+/// Drops the last MLE evaluation.
+///
+/// Meant to trip the size validation, which fails with `ProofError::MleEvaluationCountMismatch`.
+///
+/// # Panics
+///
+/// Panics if the proof has no MLE evaluations.
+#[must_use]
+pub fn remove_pcs_proof_evaluation<CP: CommitmentEvaluationProof>(
+    mut proof: QueryProof<CP>,
+) -> QueryProof<CP> {
+    proof
+        .pcs_proof_evaluations
+        .pop()
+        .expect("the proof has no MLE evaluations");
+    proof
+}
+
+/// Adds one to the MLE evaluation at `index`.
+///
+/// Meant to trip the sumcheck evaluation check, which fails with "sumcheck evaluation check
+/// failed". A plan whose result depends on that evaluation fails the result evaluation check
+/// before that.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds.
+#[must_use]
+pub fn perturb_pcs_proof_evaluation<CP: CommitmentEvaluationProof>(
+    mut proof: QueryProof<CP>,
+    index: usize,
+) -> QueryProof<CP> {
+    proof.pcs_proof_evaluations[index] += CP::Scalar::ONE;
+    proof
+}
@@ -14,7 +14,9 @@ use crate::{
         proof::ProofError,
         scalar::{Curve25519Scalar, Scalar},
     },
-    sql::proof::{FirstRoundBuilder, QueryData, SumcheckSubpolynomialType},
+    sql::proof::{
+        FirstRoundBuilder, QueryData, QueryError, ResultError, SumcheckSubpolynomialType,
+    },
 };
 use bumpalo::Bump;
 use serde::Serialize;
@@ -86,6 +88,9 @@ impl<C: Commitment> ProofPlan<C> for TrivialTestProofPlan {
     fn get_offset(&self, _accessor: &dyn MetadataAccessor) -> usize {
         self.offset
     }
+    fn max_output_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        <Self as ProofPlan<C>>::get_length(self, accessor)
+    }
     fn verifier_evaluate(
         &self,
         builder: &mut VerificationBuilder<C>,
@@ -146,6 +151,12 @@ fn we_can_verify_a_trivial_query_proof_with_a_non_zero_offset() {
     }
 }
 
+#[test]
+fn we_can_verify_a_trivial_query_proof_with_no_rows() {
+    verify_a_trivial_query_proof_with_given_offset(0, 0);
+    verify_a_trivial_query_proof_with_given_offset(0, 123);
+}
+
 #[test]
 fn verify_fails_if_the_summation_in_sumcheck_isnt_zero() {
     // set up a proof for an artificial polynomial that doesn't sum to zero
@@ -255,6 +266,9 @@ impl<C: Commitment> ProofPlan<C> for SquareTestProofPlan {
     fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
         accessor.get_offset("sxt.test".parse().unwrap())
     }
+    fn max_output_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        <Self as ProofPlan<C>>::get_length(self, accessor)
+    }
     fn verifier_evaluate(
         &self,
         builder: &mut VerificationBuilder<C>,
@@ -453,6 +467,9 @@ impl<C: Commitment> ProofPlan<C> for DoubleSquareTestProofPlan {
     fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
         accessor.get_offset("sxt.test".parse().unwrap())
     }
+    fn max_output_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        <Self as ProofPlan<C>>::get_length(self, accessor)
+    }
     fn verifier_evaluate(
         &self,
         builder: &mut VerificationBuilder<C>,
@@ -555,6 +572,148 @@ fn verify_fails_if_an_intermediate_commitment_doesnt_match() {
     assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
 }
 
+#[test]
+fn verify_fails_with_a_commitment_count_mismatch_if_a_commitment_is_added() {
+    let expr = DoubleSquareTestProofPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (mut proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    proof.commitments.push(proof.commitments[0]);
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::CommitmentCountMismatch {
+                expected: 2,
+                actual: 3
+            }
+        })
+    ));
+}
+
+#[test]
+fn verify_fails_with_an_mle_evaluation_count_mismatch_if_an_evaluation_is_removed() {
+    let expr = DoubleSquareTestProofPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (mut proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    proof.pcs_proof_evaluations.pop();
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::MleEvaluationCountMismatch {
+                expected: 3,
+                actual: 2
+            }
+        })
+    ));
+}
+
+#[test]
+fn verify_fails_with_a_column_count_mismatch_if_the_result_has_extra_columns() {
+    let expr = DoubleSquareTestProofPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, mut result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    *result.num_columns_mut() += 1;
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::ColumnCountMismatch {
+                expected: 1,
+                actual: 2
+            }
+        })
+    ));
+}
+
+#[test]
+fn verify_fails_if_the_result_has_more_rows_than_the_input() {
+    let expr = DoubleSquareTestProofPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, mut result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    result.table_length = u64::MAX;
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::ResultTooLarge { max: 2, .. }
+        })
+    ));
+}
+
+#[test]
+fn verify_fails_with_a_diagnostic_if_the_result_has_trailing_bytes() {
+    let expr = DoubleSquareTestProofPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, mut result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    result.data_mut().push(0);
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::InvalidResult {
+            source: ResultError::TrailingBytes { count: 1 }
+        })
+    ));
+}
+
+#[test]
+fn we_can_compute_the_serialized_size_of_a_proof() {
+    let expr = DoubleSquareTestProofPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, _result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let serialized_proof = postcard::to_allocvec(&proof).unwrap();
+    assert_eq!(proof.serialized_size(), serialized_proof.len());
+    let breakdown = proof.serialized_size_breakdown();
+    assert_eq!(breakdown.total(), serialized_proof.len());
+    assert_eq!(
+        breakdown.commitments,
+        postcard::to_allocvec(&proof.commitments).unwrap().len()
+    );
+    assert_eq!(
+        breakdown.evaluation_proof,
+        postcard::to_allocvec(&proof.evaluation_proof)
+            .unwrap()
+            .len()
+    );
+}
+
 #[test]
 fn verify_fails_if_an_intermediate_equation_isnt_satified() {
     // attempt to prove and verify an artificial query where
@@ -659,6 +818,9 @@ impl<C: Commitment> ProofPlan<C> for ChallengeTestProofPlan {
     fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
         accessor.get_offset("sxt.test".parse().unwrap())
     }
+    fn max_output_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        <Self as ProofPlan<C>>::get_length(self, accessor)
+    }
     fn verifier_evaluate(
         &self,
         builder: &mut VerificationBuilder<C>,
@@ -1,8 +1,10 @@
+use super::ResultError;
 use crate::base::{
     database::{OwnedTable, OwnedTableError},
     proof::ProofError,
     scalar::Scalar,
 };
+use serde::Serialize;
 use snafu::Snafu;
 
 /// Verifiable query errors
@@ -37,9 +39,30 @@ pub enum QueryError {
     /// The number of columns in the table was invalid.
     #[snafu(display("Invalid number of columns"))]
     InvalidColumnCount,
+    /// A requested result column index was not less than the number of result columns.
+    #[snafu(display("Result column index {index} is out of range for {column_count} columns"))]
+    ColumnIndexOutOfRange {
+        /// The requested index
+        index: usize,
+        /// The number of columns in the result
+        column_count: usize,
+    },
+    /// A result column index was requested more than once.
+    #[snafu(display("Result column index {index} was requested more than once"))]
+    DuplicateColumnIndex {
+        /// The repeated index
+        index: usize,
+    },
+    /// The result was not a well-formed encoding of the query's columns.
+    #[snafu(transparent)]
+    InvalidResult {
+        /// The underlying source error
+        source: ResultError,
+    },
 }
 
 /// The verified results of a query along with metadata produced by verification
+#[derive(Serialize)]
 pub struct QueryData<S: Scalar> {
     /// We use Apache Arrow's [`RecordBatch`] to represent a table
     /// result so as to allow for easy interoperability with
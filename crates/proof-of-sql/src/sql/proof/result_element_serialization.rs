@@ -46,11 +46,10 @@ impl<'a> ProvableResultElement<'a> for &'a [u8] {
         let (len_buf, sizeof_usize) =
             <usize>::decode_var(data).ok_or(QueryError::MiscellaneousDecodingError)?;
 
-        let bytes_read = len_buf + sizeof_usize;
-
-        if data.len() < bytes_read {
-            return Err(QueryError::MiscellaneousDecodingError);
-        }
+        let bytes_read = len_buf
+            .checked_add(sizeof_usize)
+            .filter(|&bytes_read| bytes_read <= data.len())
+            .ok_or(QueryError::MiscellaneousDecodingError)?;
 
         Ok((&data[sizeof_usize..bytes_read], bytes_read))
     }
@@ -110,7 +109,8 @@ pub fn decode_multiple_elements<'a, T: ProvableResultElement<'a>>(
     data: &'a [u8],
     n: usize,
 ) -> Result<(Vec<T>, usize), QueryError> {
-    let mut res = Vec::with_capacity(n);
+    // every element takes at least one byte, so don't trust `n` beyond the data we have
+    let mut res = Vec::with_capacity(n.min(data.len()));
     let mut cnt = 0;
     for _ in 0..n {
         let (val, num_read) = <T>::decode(&data[cnt..])?;
@@ -32,6 +32,10 @@ pub struct SumcheckMleEvaluations<'a, S: Scalar> {
     pub random_evaluation: S,
     /// The evaluations (at the random point generated by sumcheck) of the mles that are evaluated by the inner product argument. These are batched together and checked by a single IPA.
     pub pcs_proof_evaluations: &'a [S],
+    /// The evaluations (at the random point generated by sumcheck) of the result columns.
+    ///
+    /// The verifier computes these from the result itself, so result columns need no commitments.
+    pub result_evaluations: &'a [S],
 }
 
 #[allow(
@@ -45,20 +49,21 @@ impl<'a, S: Scalar> SumcheckMleEvaluations<'a, S> {
     /// - `evaluation_point` - the point, outputted by sumcheck, at which to evaluate the MLEs
     /// - `sumcheck_random_scalars` - the random scalars used to batch the evaluations that are proven via IPA
     /// - `pcs_proof_evaluations` - the evaluations of the MLEs that are proven via IPA
+    /// - `result_evaluations` - the evaluations of the result columns
     pub fn new(
         input_length: usize,
         output_length: usize,
         evaluation_point: &[S],
         sumcheck_random_scalars: &SumcheckRandomScalars<S>,
         pcs_proof_evaluations: &'a [S],
+        result_evaluations: &'a [S],
     ) -> Self {
         assert_eq!(
             evaluation_point.len(),
             sumcheck_random_scalars.entrywise_point.len()
         );
-        assert_eq!(input_length, sumcheck_random_scalars.table_length);
         let random_evaluation = compute_truncated_lagrange_basis_inner_product(
-            input_length,
+            sumcheck_random_scalars.range_length,
             evaluation_point,
             sumcheck_random_scalars.entrywise_point,
         );
@@ -74,6 +79,7 @@ impl<'a, S: Scalar> SumcheckMleEvaluations<'a, S> {
             output_one_evaluation,
             random_evaluation,
             pcs_proof_evaluations,
+            result_evaluations,
         }
     }
 }
@@ -0,0 +1,64 @@
+use super::{CountBuilder, ProofCounts, ProofPlan};
+use crate::base::{
+    commitment::Commitment, database::MetadataAccessor, math::log2_up, proof::ProofError,
+};
+use core::cmp;
+
+/// The size of the sumcheck argument in a proof of a query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SumcheckShape {
+    /// The degree of the sumcheck polynomial in each variable
+    ///
+    /// This is the largest number of multiplicands in any term that an operator of the query
+    /// contributes, and is never less than 2.
+    pub degree: usize,
+    /// The number of sumcheck rounds, one per variable of the sumcheck polynomial
+    pub rounds: usize,
+}
+
+/// Predict the [`SumcheckShape`] of a proof of `expr` without proving it.
+///
+/// Counting normally depends on the bit distributions in a proof. Here every bit distribution
+/// is assumed to vary in all of its bits, so the predicted degree is an upper bound: a proof may
+/// have a smaller degree if its data makes a sign decomposition constant.
+///
+/// The proof then has `rounds * (degree + 1)` sumcheck coefficients.
+pub fn expected_sumcheck_shape<C: Commitment>(
+    expr: &impl ProofPlan<C>,
+    accessor: &impl MetadataAccessor,
+) -> Result<SumcheckShape, ProofError> {
+    worst_case_counts(expr, accessor).map(|(_, shape)| shape)
+}
+
+/// Count the components of a proof of `expr` with every bit distribution assumed to vary in all
+/// of its bits, and return the counts along with the [`SumcheckShape`] they imply.
+pub(super) fn worst_case_counts<C: Commitment>(
+    expr: &impl ProofPlan<C>,
+    accessor: &impl MetadataAccessor,
+) -> Result<(ProofCounts, SumcheckShape), ProofError> {
+    let mut builder = CountBuilder::new_worst_case();
+    expr.count(&mut builder, accessor)?;
+    let counts = builder.counts()?;
+    let shape = SumcheckShape {
+        // `CompositePolynomialBuilder::make_composite_polynomial` always adds a degree 2 term.
+        degree: cmp::max(counts.sumcheck_max_multiplicands, 2),
+        rounds: cmp::max(
+            log2_up(cmp::max(
+                expr.get_length(accessor),
+                expr.max_output_length(accessor),
+            )),
+            1,
+        ),
+    };
+    Ok((counts, shape))
+}
+
+/// Predict the degree of the sumcheck polynomial in a proof of `expr`.
+///
+/// See [`expected_sumcheck_shape`].
+pub fn expected_sumcheck_degree<C: Commitment>(
+    expr: &impl ProofPlan<C>,
+    accessor: &impl MetadataAccessor,
+) -> Result<usize, ProofError> {
+    expected_sumcheck_shape(expr, accessor).map(|shape| shape.degree)
+}
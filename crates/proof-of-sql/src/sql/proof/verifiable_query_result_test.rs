@@ -65,6 +65,9 @@ impl<C: Commitment> ProofPlan<C> for EmptyTestQueryExpr {
     fn get_offset(&self, _accessor: &dyn MetadataAccessor) -> usize {
         0
     }
+    fn max_output_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        <Self as ProofPlan<C>>::get_length(self, accessor)
+    }
     fn verifier_evaluate(
         &self,
         builder: &mut VerificationBuilder<C>,
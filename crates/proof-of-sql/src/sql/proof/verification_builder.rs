@@ -1,5 +1,7 @@
 use super::{SumcheckMleEvaluations, SumcheckSubpolynomialType};
-use crate::base::{bit::BitDistribution, commitment::Commitment};
+use crate::base::{
+    bit::BitDistribution, commitment::Commitment, database::ColumnRef, map::IndexMap,
+};
 use alloc::vec::Vec;
 use num_traits::Zero;
 
@@ -15,7 +17,9 @@ pub struct VerificationBuilder<'a, C: Commitment> {
     pcs_proof_commitments: Vec<C>,
     folded_pcs_proof_evaluation: C::Scalar,
     consumed_pcs_proof_mles: usize,
+    column_mle_evaluations: IndexMap<ColumnRef, C::Scalar>,
     consumed_intermediate_mles: usize,
+    consumed_result_mles: usize,
     produced_subpolynomials: usize,
     /// The challenges used in creation of the constraints in the proof.
     /// Specifically, these are the challenges that the verifier sends to
@@ -56,7 +60,9 @@ impl<'a, C: Commitment> VerificationBuilder<'a, C> {
             pcs_proof_commitments: Vec::with_capacity(inner_product_multipliers.len()),
             folded_pcs_proof_evaluation: C::Scalar::zero(),
             consumed_pcs_proof_mles: 0,
+            column_mle_evaluations: IndexMap::default(),
             consumed_intermediate_mles: 0,
+            consumed_result_mles: 0,
             produced_subpolynomials: 0,
             post_result_challenges,
         }
@@ -83,6 +89,18 @@ impl<'a, C: Commitment> VerificationBuilder<'a, C> {
         res
     }
 
+    /// Consume the evaluation of the anchored MLE of a table column
+    ///
+    /// A column referenced more than once reuses the evaluation consumed the first time.
+    pub fn consume_column_mle(&mut self, column_ref: ColumnRef, commitment: C) -> C::Scalar {
+        if let Some(&eval) = self.column_mle_evaluations.get(&column_ref) {
+            return eval;
+        }
+        let eval = self.consume_anchored_mle(commitment);
+        self.column_mle_evaluations.insert(column_ref, eval);
+        eval
+    }
+
     /// Consume a bit distribution that describes which bits are constant
     /// and which bits varying in a column of data
     pub fn consume_bit_distribution(&mut self) -> BitDistribution {
@@ -100,6 +118,15 @@ impl<'a, C: Commitment> VerificationBuilder<'a, C> {
         self.consume_anchored_mle(commitment)
     }
 
+    /// Consume the evaluation of the MLE of a result column
+    ///
+    /// A result column is public, so its evaluation is computed from the result rather than proven
+    pub fn consume_result_mle(&mut self) -> C::Scalar {
+        let res = self.mle_evaluations.result_evaluations[self.consumed_result_mles];
+        self.consumed_result_mles += 1;
+        res
+    }
+
     /// Produce the evaluation of a subpolynomial used in sumcheck
     pub fn produce_sumcheck_subpolynomial_evaluation(
         &mut self,
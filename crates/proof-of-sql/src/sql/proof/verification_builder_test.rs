@@ -1,5 +1,11 @@
 use super::{SumcheckMleEvaluations, VerificationBuilder};
-use crate::{base::scalar::Curve25519Scalar, sql::proof::SumcheckSubpolynomialType};
+use crate::{
+    base::{
+        database::{ColumnRef, ColumnType},
+        scalar::Curve25519Scalar,
+    },
+    sql::proof::SumcheckSubpolynomialType,
+};
 use curve25519_dalek::ristretto::RistrettoPoint;
 use num_traits::Zero;
 use rand_core::OsRng;
@@ -105,6 +111,57 @@ fn we_build_up_the_folded_pcs_proof_commitment() {
     );
 }
 
+#[test]
+fn we_only_consume_the_mle_of_a_repeated_column_once() {
+    let pcs_proof_evaluations = [
+        Curve25519Scalar::from(123u64),
+        Curve25519Scalar::from(456u64),
+    ];
+    let mle_evaluations = SumcheckMleEvaluations {
+        input_length: 1,
+        num_sumcheck_variables: 1,
+        pcs_proof_evaluations: &pcs_proof_evaluations,
+        ..Default::default()
+    };
+    let mut rng = OsRng;
+    let commit_a = RistrettoPoint::random(&mut rng);
+    let commit_b = RistrettoPoint::random(&mut rng);
+    let inner_product_multipliers = [
+        Curve25519Scalar::from(10u64),
+        Curve25519Scalar::from(100u64),
+    ];
+    let mut builder = VerificationBuilder::new(
+        0,
+        mle_evaluations,
+        &[][..],
+        &[][..],
+        &[][..],
+        &inner_product_multipliers,
+        Vec::new(),
+    );
+    let t = "sxt.t".parse().unwrap();
+    let a = ColumnRef::new(t, "a".parse().unwrap(), ColumnType::BigInt);
+    let b = ColumnRef::new(t, "b".parse().unwrap(), ColumnType::BigInt);
+    assert_eq!(
+        builder.consume_column_mle(a, commit_a),
+        Curve25519Scalar::from(123u64)
+    );
+    assert_eq!(
+        builder.consume_column_mle(b, commit_b),
+        Curve25519Scalar::from(456u64)
+    );
+    assert_eq!(
+        builder.consume_column_mle(a, commit_a),
+        Curve25519Scalar::from(123u64)
+    );
+    assert_eq!(builder.pcs_proof_commitments(), &[commit_a, commit_b]);
+    assert_eq!(
+        builder.folded_pcs_proof_evaluation(),
+        inner_product_multipliers[0] * Curve25519Scalar::from(123u64)
+            + inner_product_multipliers[1] * Curve25519Scalar::from(456u64)
+    );
+}
+
 #[test]
 fn we_can_consume_post_result_challenges_in_proof_builder() {
     let mut builder = VerificationBuilder::<RistrettoPoint>::new(
@@ -133,3 +190,33 @@ fn we_can_consume_post_result_challenges_in_proof_builder() {
         builder.consume_post_result_challenge()
     );
 }
+
+#[test]
+fn we_consume_result_mles_without_adding_them_to_the_pcs_proof() {
+    let result_evaluations = [
+        Curve25519Scalar::from(123u64),
+        Curve25519Scalar::from(456u64),
+    ];
+    let mle_evaluations = SumcheckMleEvaluations {
+        input_length: 1,
+        num_sumcheck_variables: 1,
+        result_evaluations: &result_evaluations,
+        ..Default::default()
+    };
+    let mut builder = VerificationBuilder::<RistrettoPoint>::new(
+        0,
+        mle_evaluations,
+        &[][..],
+        &[][..],
+        &[][..],
+        &[][..],
+        Vec::new(),
+    );
+    assert_eq!(builder.consume_result_mle(), Curve25519Scalar::from(123u64));
+    assert_eq!(builder.consume_result_mle(), Curve25519Scalar::from(456u64));
+    assert_eq!(builder.pcs_proof_commitments(), &[]);
+    assert_eq!(
+        builder.folded_pcs_proof_evaluation(),
+        Curve25519Scalar::zero()
+    );
+}
@@ -0,0 +1,76 @@
+use super::{ProvableQueryResult, QueryError, QueryProof, QueryProofDecodeError};
+use crate::{
+    base::commitment::{CommitmentEvaluationProof, QueryCommitments},
+    sql::proof_plans::DynProofPlan,
+};
+use alloc::vec::Vec;
+use serde::{de::DeserializeOwned, Serialize};
+use snafu::Snafu;
+
+/// Errors from verifying a query with [`verify_serialized`].
+#[derive(Snafu, Debug)]
+pub enum SerializedVerificationError {
+    /// The proof could not be decoded.
+    #[snafu(transparent)]
+    InvalidProof {
+        /// The underlying source error
+        source: QueryProofDecodeError,
+    },
+    /// One of the other inputs could not be deserialized.
+    #[snafu(display("Failed to deserialize the {input}: {error}"))]
+    Deserialization {
+        /// The input that failed to deserialize
+        input: &'static str,
+        /// The underlying `postcard` error
+        error: postcard::Error,
+    },
+    /// The proof failed to verify.
+    #[snafu(transparent)]
+    Verification {
+        /// The underlying source error
+        source: QueryError,
+    },
+}
+
+/// Deserialize one of the `postcard` encoded inputs of [`verify_serialized`].
+fn deserialize<T: DeserializeOwned>(
+    bytes: &[u8],
+    input: &'static str,
+) -> Result<T, SerializedVerificationError> {
+    postcard::from_bytes(bytes)
+        .map_err(|error| SerializedVerificationError::Deserialization { input, error })
+}
+
+/// Verify a query proof whose inputs are all serialized, and return the serialized result.
+///
+/// This lets callers such as a browser verify a proof without constructing any of the proof types.
+/// - `proof_bytes` is a proof encoded with [`QueryProof::to_bytes`].
+/// - `plan_bytes` is the `postcard` encoding of the [`DynProofPlan`] that was proven.
+/// - `commitments_bytes` is the `postcard` encoding of the [`QueryCommitments`] of every table the plan reads.
+/// - `result_bytes` is the `postcard` encoding of the [`ProvableQueryResult`] returned by the prover.
+///
+/// On success, the `postcard` encoding of the verified [`QueryData`](super::QueryData) is returned.
+///
+/// # Panics
+///
+/// Panics if the verified result fails to serialize, which should never happen.
+pub fn verify_serialized<CP>(
+    proof_bytes: &[u8],
+    plan_bytes: &[u8],
+    commitments_bytes: &[u8],
+    result_bytes: &[u8],
+    setup: &CP::VerifierPublicSetup<'_>,
+) -> Result<Vec<u8>, SerializedVerificationError>
+where
+    CP: CommitmentEvaluationProof + DeserializeOwned,
+    CP::Commitment: Serialize + DeserializeOwned,
+    CP::Scalar: Serialize,
+{
+    let proof = QueryProof::<CP>::from_bytes(proof_bytes)?;
+    let plan: DynProofPlan<CP::Commitment> = deserialize(plan_bytes, "proof plan")?;
+    let commitments: QueryCommitments<CP::Commitment> =
+        deserialize(commitments_bytes, "query commitments")?;
+    let result: ProvableQueryResult = deserialize(result_bytes, "query result")?;
+    let data = proof.verify(&plan, &commitments, &result, setup)?;
+    Ok(postcard::to_allocvec(&data).expect("Failed to serialize query data"))
+}
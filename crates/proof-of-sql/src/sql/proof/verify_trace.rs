@@ -0,0 +1,28 @@
+use crate::base::commitment::Commitment;
+use alloc::vec::Vec;
+
+/// The intermediate quantities computed while verifying a [`QueryProof`](super::QueryProof).
+///
+/// Passing a trace in [`VerifyOptions::trace`](super::VerifyOptions::trace) fills in each field
+/// as soon as the verifier computes it, so when a proof fails to verify the trace shows which
+/// quantity diverged. Fields the verifier never reached are left empty.
+///
+/// Every quantity is computed from the proof and public inputs, so recording it reveals nothing
+/// that the verifier doesn't already know.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifyTrace<C: Commitment> {
+    /// The point that the sumcheck subclaim is evaluated at
+    pub evaluation_point: Vec<C::Scalar>,
+    /// The evaluation of the sumcheck polynomial claimed by the sumcheck subclaim
+    pub expected_sumcheck_evaluation: Option<C::Scalar>,
+    /// The evaluation of the sumcheck polynomial computed by the verifier
+    pub sumcheck_evaluation: Option<C::Scalar>,
+    /// The evaluations of the result columns computed by the plan's verifier
+    pub verifier_result_evaluations: Vec<C::Scalar>,
+    /// The evaluations of the result columns computed from the result itself
+    pub result_evaluations: Vec<C::Scalar>,
+    /// The random linear combination of the commitments whose evaluations are proven
+    pub folded_pcs_proof_commitment: Option<C>,
+    /// The same random linear combination of the claimed evaluations
+    pub folded_pcs_proof_evaluation: Option<C::Scalar>,
+}
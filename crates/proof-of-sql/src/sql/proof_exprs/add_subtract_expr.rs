@@ -33,6 +33,11 @@ impl<C: Commitment> AddSubtractExpr<C> {
             is_subtract,
         }
     }
+
+    /// See [`DynProofExpr::magnitude_bits`]
+    pub(super) fn magnitude_bits(&self) -> Option<u32> {
+        Some(self.lhs.magnitude_bits()?.max(self.rhs.magnitude_bits()?) + 1)
+    }
 }
 
 impl<C: Commitment> ProofExpr<C> for AddSubtractExpr<C> {
@@ -36,9 +36,11 @@ impl<C: Commitment> ProofExpr<C> for AggregateExpr<C> {
 
     fn data_type(&self) -> ColumnType {
         match self.op {
-            AggregationOperator::Count => ColumnType::BigInt,
-            AggregationOperator::Sum => self.expr.data_type(),
-            _ => todo!("Aggregation operator not supported here yet"),
+            AggregationOperator::Count | AggregationOperator::CountDistinct => ColumnType::BigInt,
+            AggregationOperator::Sum | AggregationOperator::Max | AggregationOperator::Min => {
+                self.expr.data_type()
+            }
+            AggregationOperator::First => todo!("Aggregation operator not supported here yet"),
         }
     }
 
@@ -0,0 +1,171 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        map::IndexSet,
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        parse::try_case_column_type,
+        proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+    },
+};
+use alloc::{boxed::Box, vec};
+use bumpalo::Bump;
+use num_traits::One;
+use serde::{Deserialize, Serialize};
+
+/// Provable `CASE WHEN predicate THEN then ELSE otherwise END` expression
+///
+/// The prover commits to the selected values `z` and proves that
+/// `z = p * then + (1 - p) * otherwise` elementwise. The predicate `p` is a boolean expression,
+/// whose values are always zero or one, so `z` takes the value of exactly one of the branches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseExpr<C: Commitment> {
+    predicate: Box<DynProofExpr<C>>,
+    then: Box<DynProofExpr<C>>,
+    otherwise: Box<DynProofExpr<C>>,
+}
+
+impl<C: Commitment> CaseExpr<C> {
+    /// Create a new `CASE WHEN` expression
+    pub fn new(
+        predicate: Box<DynProofExpr<C>>,
+        then: Box<DynProofExpr<C>>,
+        otherwise: Box<DynProofExpr<C>>,
+    ) -> Self {
+        Self {
+            predicate,
+            then,
+            otherwise,
+        }
+    }
+
+    /// See [`DynProofExpr::magnitude_bits`]
+    pub(super) fn magnitude_bits(&self) -> Option<u32> {
+        Some(
+            self.then
+                .magnitude_bits()?
+                .max(self.otherwise.magnitude_bits()?),
+        )
+    }
+}
+
+/// Select the value of `then` where `predicate` holds and the value of `otherwise` elsewhere.
+///
+/// # Panics
+/// Panics if `predicate` is not a boolean column or the columns have different lengths.
+fn select_columns<'a, S: Scalar>(
+    predicate: &Column<'a, S>,
+    then: &Column<'a, S>,
+    otherwise: &Column<'a, S>,
+    alloc: &'a Bump,
+) -> &'a [S] {
+    let predicate = predicate
+        .as_boolean()
+        .expect("the predicate should be a boolean column");
+    assert!(
+        then.len() == predicate.len() && otherwise.len() == predicate.len(),
+        "the predicate and branches should have the same length"
+    );
+    alloc.alloc_slice_fill_with(predicate.len(), |i| {
+        if predicate[i] {
+            then.scalar_at(i).unwrap()
+        } else {
+            otherwise.scalar_at(i).unwrap()
+        }
+    })
+}
+
+impl<C: Commitment> ProofExpr<C> for CaseExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.predicate.count(builder)?;
+        self.then.count(builder)?;
+        self.otherwise.count(builder)?;
+        builder.count_subpolynomials(1);
+        builder.count_intermediate_mles(1);
+        builder.count_degree(3);
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        try_case_column_type(self.then.data_type(), self.otherwise.data_type())
+            .expect("Failed to find the type of the branches")
+    }
+
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let predicate = self
+            .predicate
+            .result_evaluate(table_length, alloc, accessor);
+        let then = self.then.result_evaluate(table_length, alloc, accessor);
+        let otherwise = self
+            .otherwise
+            .result_evaluate(table_length, alloc, accessor);
+        Column::Scalar(select_columns(&predicate, &then, &otherwise, alloc))
+    }
+
+    #[tracing::instrument(name = "CaseExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, C::Scalar>,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let predicate = self.predicate.prover_evaluate(builder, alloc, accessor);
+        let then = self.then.prover_evaluate(builder, alloc, accessor);
+        let otherwise = self.otherwise.prover_evaluate(builder, alloc, accessor);
+
+        // selected
+        let selected = select_columns(&predicate, &then, &otherwise, alloc);
+        builder.produce_intermediate_mle(selected);
+
+        // subpolynomial: selected - predicate * then - otherwise + predicate * otherwise
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (C::Scalar::one(), vec![Box::new(selected)]),
+                (-C::Scalar::one(), vec![Box::new(predicate), Box::new(then)]),
+                (-C::Scalar::one(), vec![Box::new(otherwise)]),
+                (
+                    C::Scalar::one(),
+                    vec![Box::new(predicate), Box::new(otherwise)],
+                ),
+            ],
+        );
+        Column::Scalar(selected)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let predicate = self.predicate.verifier_evaluate(builder, accessor)?;
+        let then = self.then.verifier_evaluate(builder, accessor)?;
+        let otherwise = self.otherwise.verifier_evaluate(builder, accessor)?;
+
+        // selected
+        let selected = builder.consume_intermediate_mle();
+
+        // subpolynomial: selected - predicate * then - otherwise + predicate * otherwise
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            selected - predicate * then - otherwise + predicate * otherwise,
+        );
+
+        Ok(selected)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.predicate.get_column_references(columns);
+        self.then.get_column_references(columns);
+        self.otherwise.get_column_references(columns);
+    }
+}
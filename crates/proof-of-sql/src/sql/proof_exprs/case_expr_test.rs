@@ -0,0 +1,105 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, Column, OwnedTableTestAccessor},
+        scalar::Curve25519Scalar,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr, ProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use bumpalo::Bump;
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+// select case when a >= 0 then a else 0 end as r, b from sxt.t where case when c then a else b end <= 5
+#[test]
+fn we_can_prove_a_typical_case_when_query() {
+    let data = owned_table::<Curve25519Scalar>([
+        int("a", [-7, 0, 5, 9, -2]),
+        bigint("b", [100_i64, 7, -200, 0, 3]),
+        boolean("c", [true, false, false, true, false]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![
+            aliased_plan(
+                case_when(
+                    gte(column(t, "a", &accessor), const_bigint(0)),
+                    column(t, "a", &accessor),
+                    const_bigint(0),
+                ),
+                "r",
+            ),
+            col_expr_plan(t, "b", &accessor),
+        ],
+        tab(t),
+        lte(
+            case_when(
+                column(t, "c", &accessor),
+                column(t, "a", &accessor),
+                column(t, "b", &accessor),
+            ),
+            const_bigint(5),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("r", [0, 5, 0]), bigint("b", [100, -200, 3])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_compute_the_correct_output_of_a_case_when_expr_using_result_evaluate() {
+    let data = owned_table::<Curve25519Scalar>([
+        decimal75("a", 5, 2, [100, 200, 300]),
+        decimal75("b", 5, 2, [-1, -2, -3]),
+        boolean("c", [true, false, true]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let case_expr: DynProofExpr<RistrettoPoint> = case_when(
+        column(t, "c", &accessor),
+        column(t, "a", &accessor),
+        column(t, "b", &accessor),
+    );
+    let alloc = Bump::new();
+    let res = case_expr.result_evaluate(3, &alloc, &accessor);
+    let expected_scalars = [100, -2, 300].map(Curve25519Scalar::from);
+    assert_eq!(res, Column::Scalar(&expected_scalars));
+}
+
+#[test]
+fn we_cannot_create_a_case_when_expr_with_a_nonboolean_predicate_or_mismatched_branches() {
+    let data = owned_table::<Curve25519Scalar>([
+        bigint("a", [1, 2]),
+        decimal75("d", 5, 2, [100, 200]),
+        varchar("v", ["x", "y"]),
+        boolean("c", [true, false]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let try_new_case = |predicate: &str, then: &str, otherwise: &str| {
+        DynProofExpr::<RistrettoPoint>::try_new_case(
+            column(t, predicate, &accessor),
+            column(t, then, &accessor),
+            column(t, otherwise, &accessor),
+        )
+    };
+    assert!(matches!(
+        try_new_case("a", "a", "a"),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+    assert!(matches!(
+        try_new_case("c", "a", "d"),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+    assert!(matches!(
+        try_new_case("c", "v", "v"),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+}
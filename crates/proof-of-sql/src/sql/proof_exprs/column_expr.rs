@@ -49,7 +49,7 @@ impl<C: Commitment> ColumnExpr<C> {
 impl<C: Commitment> ProofExpr<C> for ColumnExpr<C> {
     /// Count the number of proof terms needed by this expression
     fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
-        builder.count_anchored_mles(1);
+        builder.count_column_mle(self.column_ref);
         Ok(())
     }
 
@@ -80,7 +80,7 @@ impl<C: Commitment> ProofExpr<C> for ColumnExpr<C> {
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         let column = accessor.get_column(self.column_ref);
-        builder.produce_anchored_mle(column);
+        builder.produce_column_mle(self.column_ref, column);
         column
     }
 
@@ -91,8 +91,8 @@ impl<C: Commitment> ProofExpr<C> for ColumnExpr<C> {
         builder: &mut VerificationBuilder<C>,
         accessor: &dyn CommitmentAccessor<C>,
     ) -> Result<C::Scalar, ProofError> {
-        let col_commit = accessor.get_commitment(self.column_ref);
-        Ok(builder.consume_anchored_mle(col_commit))
+        let col_commit = accessor.try_get_commitment(self.column_ref)?;
+        Ok(builder.consume_column_mle(self.column_ref, col_commit))
     }
 
     /// Insert in the [`IndexSet`] `columns` all the column
@@ -1,6 +1,7 @@
 use super::{
-    AddSubtractExpr, AggregateExpr, AndExpr, ColumnExpr, EqualsExpr, InequalityExpr, LiteralExpr,
-    MultiplyExpr, NotExpr, OrExpr, ProofExpr,
+    AddSubtractExpr, AggregateExpr, AndExpr, CaseExpr, ColumnExpr, EqualsExpr, InListExpr,
+    InequalityExpr, LiteralExpr, ModExpr, MultiplyExpr, NotExpr, OrExpr, OverflowExpr,
+    OverflowMode, ProofExpr,
 };
 use crate::{
     base::{
@@ -10,16 +11,24 @@ use crate::{
         proof::ProofError,
     },
     sql::{
-        parse::{type_check_binary_operation, ConversionError, ConversionResult},
+        parse::{
+            try_case_column_type, type_check_binary_operation, ConversionError, ConversionResult,
+        },
         proof::{CountBuilder, FinalRoundBuilder, VerificationBuilder},
     },
 };
-use alloc::{boxed::Box, string::ToString};
+use alloc::{boxed::Box, format, string::ToString, vec::Vec};
 use bumpalo::Bump;
 use core::fmt::Debug;
 use proof_of_sql_parser::intermediate_ast::{AggregationOperator, BinaryOperator};
 use serde::{Deserialize, Serialize};
 
+/// The largest magnitude, in bits, of the exact value of arithmetic that wraps or saturates
+///
+/// The prover computes these values as `i128`s, which also hold them after they are shifted by the
+/// minimum of a 64 bit type.
+const MAX_OVERFLOW_MAGNITUDE_BITS: u32 = 126;
+
 /// Enum of AST column expression types that implement `ProofExpr`. Is itself a `ProofExpr`.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum DynProofExpr<C: Commitment> {
@@ -37,12 +46,20 @@ pub enum DynProofExpr<C: Commitment> {
     Equals(EqualsExpr<C>),
     /// Provable AST expression for an inequality expression
     Inequality(InequalityExpr<C>),
+    /// Provable AST expression for an `IN` list expression
+    InList(InListExpr<C>),
     /// Provable numeric `+` / `-` expression
     AddSubtract(AddSubtractExpr<C>),
     /// Provable numeric `*` expression
     Multiply(MultiplyExpr<C>),
+    /// Provable integer `%` expression with a constant modulus
+    Mod(ModExpr<C>),
     /// Provable aggregate expression
     Aggregate(AggregateExpr<C>),
+    /// Provable integer arithmetic that wraps or saturates instead of overflowing
+    Overflow(OverflowExpr<C>),
+    /// Provable `CASE WHEN` expression
+    Case(CaseExpr<C>),
 }
 impl<C: Commitment> DynProofExpr<C> {
     /// Create column expression
@@ -83,6 +100,27 @@ impl<C: Commitment> DynProofExpr<C> {
             })
         }
     }
+    /// Create a new `IN` list expression
+    pub fn try_new_in_list(
+        expr: DynProofExpr<C>,
+        list: Vec<LiteralValue<C::Scalar>>,
+    ) -> ConversionResult<Self> {
+        if list.is_empty() {
+            return Err(ConversionError::InvalidExpression {
+                expression: "IN list must not be empty".to_string(),
+            });
+        }
+        let datatype = expr.data_type();
+        if let Some(value) = list.iter().find(|value| {
+            !type_check_binary_operation(&datatype, &value.column_type(), BinaryOperator::Equal)
+        }) {
+            return Err(ConversionError::DataTypeMismatch {
+                left_type: datatype.to_string(),
+                right_type: value.column_type().to_string(),
+            });
+        }
+        Ok(Self::InList(InListExpr::new(Box::new(expr), list)))
+    }
     /// Create a new inequality expression
     pub fn try_new_inequality(
         lhs: DynProofExpr<C>,
@@ -162,6 +200,117 @@ impl<C: Commitment> DynProofExpr<C> {
         }
     }
 
+    /// Create a new modulo expression with a constant modulus
+    pub fn try_new_mod(expr: DynProofExpr<C>, modulus: i64) -> ConversionResult<Self> {
+        let datatype = expr.data_type();
+        if !datatype.is_integer() {
+            return Err(ConversionError::InvalidDataType {
+                expected: ColumnType::BigInt,
+                actual: datatype,
+            });
+        }
+        if modulus <= 0 {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!("modulus must be positive, got {modulus}"),
+            });
+        }
+        Ok(Self::Mod(ModExpr::new(Box::new(expr), modulus)))
+    }
+
+    /// Create a new add expression that handles overflow with `mode`
+    pub fn try_new_add_with_overflow_mode(
+        lhs: DynProofExpr<C>,
+        rhs: DynProofExpr<C>,
+        mode: OverflowMode,
+    ) -> ConversionResult<Self> {
+        Self::try_new_add(lhs, rhs)?.try_with_overflow_mode(mode)
+    }
+
+    /// Create a new subtract expression that handles overflow with `mode`
+    pub fn try_new_subtract_with_overflow_mode(
+        lhs: DynProofExpr<C>,
+        rhs: DynProofExpr<C>,
+        mode: OverflowMode,
+    ) -> ConversionResult<Self> {
+        Self::try_new_subtract(lhs, rhs)?.try_with_overflow_mode(mode)
+    }
+
+    /// Create a new multiply expression that handles overflow with `mode`
+    pub fn try_new_multiply_with_overflow_mode(
+        lhs: DynProofExpr<C>,
+        rhs: DynProofExpr<C>,
+        mode: OverflowMode,
+    ) -> ConversionResult<Self> {
+        Self::try_new_multiply(lhs, rhs)?.try_with_overflow_mode(mode)
+    }
+
+    /// Handle overflow of this arithmetic expression with `mode`
+    ///
+    /// Only integer types of at most 64 bits can wrap or saturate, and the exact value of the
+    /// arithmetic must fit in [`MAX_OVERFLOW_MAGNITUDE_BITS`] bits so that the prover can compute it.
+    fn try_with_overflow_mode(self, mode: OverflowMode) -> ConversionResult<Self> {
+        if mode == OverflowMode::Reject {
+            return Ok(self);
+        }
+        let datatype = self.data_type();
+        if !matches!(
+            datatype,
+            ColumnType::TinyInt | ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt
+        ) {
+            return Err(ConversionError::InvalidDataType {
+                expected: ColumnType::BigInt,
+                actual: datatype,
+            });
+        }
+        if !self
+            .magnitude_bits()
+            .is_some_and(|bits| bits <= MAX_OVERFLOW_MAGNITUDE_BITS)
+        {
+            return Err(ConversionError::Unprovable {
+                error: format!(
+                    "the exact value of arithmetic that wraps or saturates must have at most {MAX_OVERFLOW_MAGNITUDE_BITS} bits"
+                ),
+            });
+        }
+        Ok(Self::Overflow(OverflowExpr::new(Box::new(self), mode)))
+    }
+
+    /// An upper bound `m` with `|x| <= 2^m` for every exact integer value `x` of this expression,
+    /// or `None` if its values are not integers of bounded size
+    ///
+    /// Arithmetic is computed exactly in the scalar field, so the bound grows with every operation
+    /// below the point where the result is converted to its type.
+    pub(super) fn magnitude_bits(&self) -> Option<u32> {
+        match self {
+            DynProofExpr::AddSubtract(expr) => expr.magnitude_bits(),
+            DynProofExpr::Multiply(expr) => expr.magnitude_bits(),
+            DynProofExpr::Case(expr) => expr.magnitude_bits(),
+            _ => match self.data_type() {
+                ColumnType::TinyInt => Some(7),
+                ColumnType::SmallInt => Some(15),
+                ColumnType::Int => Some(31),
+                ColumnType::BigInt => Some(63),
+                ColumnType::Int128 => Some(127),
+                _ => None,
+            },
+        }
+    }
+
+    /// Create a new `CASE WHEN predicate THEN then ELSE otherwise END` expression
+    pub fn try_new_case(
+        predicate: DynProofExpr<C>,
+        then: DynProofExpr<C>,
+        otherwise: DynProofExpr<C>,
+    ) -> ConversionResult<Self> {
+        predicate.check_data_type(ColumnType::Boolean)?;
+        try_case_column_type(then.data_type(), otherwise.data_type())?;
+        Ok(Self::Case(CaseExpr::new(
+            Box::new(predicate),
+            Box::new(then),
+            Box::new(otherwise),
+        )))
+    }
+
     /// Create a new aggregate expression
     pub fn new_aggregate(op: AggregationOperator, expr: DynProofExpr<C>) -> Self {
         Self::Aggregate(AggregateExpr::new(op, Box::new(expr)))
@@ -190,9 +339,13 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Literal(expr) => ProofExpr::<C>::count(expr, builder),
             DynProofExpr::Equals(expr) => ProofExpr::<C>::count(expr, builder),
             DynProofExpr::Inequality(expr) => ProofExpr::<C>::count(expr, builder),
+            DynProofExpr::InList(expr) => ProofExpr::<C>::count(expr, builder),
             DynProofExpr::AddSubtract(expr) => ProofExpr::<C>::count(expr, builder),
             DynProofExpr::Multiply(expr) => ProofExpr::<C>::count(expr, builder),
+            DynProofExpr::Mod(expr) => ProofExpr::<C>::count(expr, builder),
             DynProofExpr::Aggregate(expr) => ProofExpr::<C>::count(expr, builder),
+            DynProofExpr::Overflow(expr) => ProofExpr::<C>::count(expr, builder),
+            DynProofExpr::Case(expr) => ProofExpr::<C>::count(expr, builder),
         }
     }
 
@@ -201,13 +354,17 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Column(expr) => expr.data_type(),
             DynProofExpr::AddSubtract(expr) => expr.data_type(),
             DynProofExpr::Multiply(expr) => expr.data_type(),
+            DynProofExpr::Mod(expr) => expr.data_type(),
             DynProofExpr::Aggregate(expr) => expr.data_type(),
+            DynProofExpr::Overflow(expr) => expr.data_type(),
+            DynProofExpr::Case(expr) => expr.data_type(),
             DynProofExpr::Literal(expr) => ProofExpr::<C>::data_type(expr),
             DynProofExpr::And(_)
             | DynProofExpr::Or(_)
             | DynProofExpr::Not(_)
             | DynProofExpr::Equals(_)
-            | DynProofExpr::Inequality(_) => ColumnType::Boolean,
+            | DynProofExpr::Inequality(_)
+            | DynProofExpr::InList(_) => ColumnType::Boolean,
         }
     }
 
@@ -239,15 +396,27 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Inequality(expr) => {
                 ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
             }
+            DynProofExpr::InList(expr) => {
+                ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
             DynProofExpr::AddSubtract(expr) => {
                 ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
             }
             DynProofExpr::Multiply(expr) => {
                 ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
             }
+            DynProofExpr::Mod(expr) => {
+                ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
             DynProofExpr::Aggregate(expr) => {
                 ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
             }
+            DynProofExpr::Overflow(expr) => {
+                ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
+            DynProofExpr::Case(expr) => {
+                ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
         }
     }
 
@@ -279,15 +448,27 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Inequality(expr) => {
                 ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
             }
+            DynProofExpr::InList(expr) => {
+                ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
             DynProofExpr::AddSubtract(expr) => {
                 ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
             }
             DynProofExpr::Multiply(expr) => {
                 ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
             }
+            DynProofExpr::Mod(expr) => {
+                ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
             DynProofExpr::Aggregate(expr) => {
                 ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
             }
+            DynProofExpr::Overflow(expr) => {
+                ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
+            DynProofExpr::Case(expr) => {
+                ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
         }
     }
 
@@ -306,9 +487,13 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Literal(expr) => expr.verifier_evaluate(builder, accessor),
             DynProofExpr::Equals(expr) => expr.verifier_evaluate(builder, accessor),
             DynProofExpr::Inequality(expr) => expr.verifier_evaluate(builder, accessor),
+            DynProofExpr::InList(expr) => expr.verifier_evaluate(builder, accessor),
             DynProofExpr::AddSubtract(expr) => expr.verifier_evaluate(builder, accessor),
             DynProofExpr::Multiply(expr) => expr.verifier_evaluate(builder, accessor),
+            DynProofExpr::Mod(expr) => expr.verifier_evaluate(builder, accessor),
             DynProofExpr::Aggregate(expr) => expr.verifier_evaluate(builder, accessor),
+            DynProofExpr::Overflow(expr) => expr.verifier_evaluate(builder, accessor),
+            DynProofExpr::Case(expr) => expr.verifier_evaluate(builder, accessor),
         }
     }
 
@@ -321,9 +506,13 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Literal(expr) => ProofExpr::<C>::get_column_references(expr, columns),
             DynProofExpr::Equals(expr) => ProofExpr::<C>::get_column_references(expr, columns),
             DynProofExpr::Inequality(expr) => ProofExpr::<C>::get_column_references(expr, columns),
+            DynProofExpr::InList(expr) => ProofExpr::<C>::get_column_references(expr, columns),
             DynProofExpr::AddSubtract(expr) => ProofExpr::<C>::get_column_references(expr, columns),
             DynProofExpr::Multiply(expr) => ProofExpr::<C>::get_column_references(expr, columns),
+            DynProofExpr::Mod(expr) => ProofExpr::<C>::get_column_references(expr, columns),
             DynProofExpr::Aggregate(expr) => ProofExpr::<C>::get_column_references(expr, columns),
+            DynProofExpr::Overflow(expr) => ProofExpr::<C>::get_column_references(expr, columns),
+            DynProofExpr::Case(expr) => ProofExpr::<C>::get_column_references(expr, columns),
         }
     }
 }
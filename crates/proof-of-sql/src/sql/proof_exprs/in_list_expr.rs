@@ -0,0 +1,171 @@
+use super::{
+    count_equals_zero, prover_evaluate_equals_zero, result_evaluate_equals_zero,
+    scale_and_add_subtract_eval, scale_and_subtract, verifier_evaluate_equals_zero, DynProofExpr,
+    ProofExpr,
+};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, LiteralValue},
+        map::IndexSet,
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bumpalo::Bump;
+use num_traits::One;
+use serde::{Deserialize, Serialize};
+
+/// Provable AST expression for `expr IN (v_1, ..., v_k)`
+///
+/// The expression is in the list exactly when `(expr - v_1) * ... * (expr - v_k)` is zero.
+/// Each partial product is committed and the full product goes through the same zero check
+/// as an equality, so a list of `k` values costs `k + 1` intermediate MLEs instead of the
+/// `3k - 1` needed by a chain of `OR`ed equalities.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InListExpr<C: Commitment> {
+    expr: Box<DynProofExpr<C>>,
+    list: Vec<LiteralValue<C::Scalar>>,
+}
+
+impl<C: Commitment> InListExpr<C> {
+    /// Create a new `IN` list expression
+    pub fn new(expr: Box<DynProofExpr<C>>, list: Vec<LiteralValue<C::Scalar>>) -> Self {
+        Self { expr, list }
+    }
+
+    /// Compute `expr - v_i` for every value in the list, scaled as in an equality
+    ///
+    /// # Panics
+    /// Panics if a value cannot be scaled to the type of `expr`, which the constructor rules out.
+    fn differences<'a>(
+        &self,
+        alloc: &'a Bump,
+        column: Column<'a, C::Scalar>,
+    ) -> Vec<&'a [C::Scalar]> {
+        let scale = self.expr.data_type().scale().unwrap_or(0);
+        self.list
+            .iter()
+            .map(|value| {
+                let value_column = Column::from_literal_with_length(value, column.len(), alloc);
+                let value_scale = value.column_type().scale().unwrap_or(0);
+                scale_and_subtract(alloc, column, value_column, scale, value_scale, true)
+                    .expect("Failed to scale and subtract")
+            })
+            .collect()
+    }
+}
+
+/// Multiply two columns element-wise
+fn multiply_slices<'a, S: Scalar>(alloc: &'a Bump, lhs: &[S], rhs: &[S]) -> &'a [S] {
+    alloc.alloc_slice_fill_with(lhs.len(), |i| lhs[i] * rhs[i])
+}
+
+impl<C: Commitment> ProofExpr<C> for InListExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.expr.count(builder)?;
+        let num_products = self.list.len().saturating_sub(1);
+        builder.count_subpolynomials(num_products);
+        builder.count_intermediate_mles(num_products);
+        builder.count_degree(3);
+        count_equals_zero(builder);
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        ColumnType::Boolean
+    }
+
+    #[tracing::instrument(name = "InListExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let column = self.expr.result_evaluate(table_length, alloc, accessor);
+        let product = self
+            .differences(alloc, column)
+            .into_iter()
+            .reduce(|product, difference| multiply_slices(alloc, product, difference))
+            .expect("IN lists are never empty");
+        Column::Boolean(result_evaluate_equals_zero(table_length, alloc, product))
+    }
+
+    #[tracing::instrument(name = "InListExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, C::Scalar>,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let column = self.expr.prover_evaluate(builder, alloc, accessor);
+        let product = self
+            .differences(alloc, column)
+            .into_iter()
+            .reduce(|product, difference| {
+                // next_product
+                let next_product = multiply_slices(alloc, product, difference);
+                builder.produce_intermediate_mle(next_product);
+
+                // subpolynomial: next_product - product * difference
+                builder.produce_sumcheck_subpolynomial(
+                    SumcheckSubpolynomialType::Identity,
+                    vec![
+                        (C::Scalar::one(), vec![Box::new(next_product)]),
+                        (
+                            -C::Scalar::one(),
+                            vec![Box::new(product), Box::new(difference)],
+                        ),
+                    ],
+                );
+                next_product
+            })
+            .expect("IN lists are never empty");
+        Column::Boolean(prover_evaluate_equals_zero(builder, alloc, product))
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let eval = self.expr.verifier_evaluate(builder, accessor)?;
+        let scale = self.expr.data_type().scale().unwrap_or(0);
+        let one_eval = builder.mle_evaluations.input_one_evaluation;
+        let product_eval = self
+            .list
+            .iter()
+            .map(|value| {
+                let value_scale = value.column_type().scale().unwrap_or(0);
+                scale_and_add_subtract_eval(
+                    eval,
+                    one_eval * value.to_scalar(),
+                    scale,
+                    value_scale,
+                    true,
+                )
+            })
+            .reduce(|product_eval, difference_eval| {
+                // next_product
+                let next_product_eval = builder.consume_intermediate_mle();
+
+                // subpolynomial: next_product - product * difference
+                builder.produce_sumcheck_subpolynomial_evaluation(
+                    &SumcheckSubpolynomialType::Identity,
+                    next_product_eval - product_eval * difference_eval,
+                );
+                next_product_eval
+            })
+            .ok_or(ProofError::VerificationError {
+                error: "IN list must not be empty",
+            })?;
+        Ok(verifier_evaluate_equals_zero(builder, product_eval))
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{
+            owned_table_utility::*, Column, LiteralValue, OwnedTable, OwnedTableTestAccessor,
+        },
+        math::decimal::Precision,
+        scalar::Curve25519Scalar,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr, ProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use bumpalo::Bump;
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+#[test]
+fn we_can_prove_an_in_list_query_with_no_rows() {
+    let data: OwnedTable<Curve25519Scalar> =
+        owned_table([bigint("a", [0; 0]), varchar("b", [""; 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["b"], &accessor),
+        tab(t),
+        in_list(
+            column(t, "a", &accessor),
+            [LiteralValue::BigInt(1), LiteralValue::BigInt(3)],
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([varchar("b", [""; 0])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_an_in_list_query_with_a_single_value() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1, 2, 3, 1]),
+        varchar("b", ["t", "ghi", "jj", "f"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["b"], &accessor),
+        tab(t),
+        in_list(column(t, "a", &accessor), [LiteralValue::BigInt(1)]),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([varchar("b", ["t", "f"])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_an_in_list_query_with_multiple_values() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1, 2, 3, 7, 0, 3]),
+        varchar("b", ["t", "ghi", "jj", "f", "abc", "x"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        in_list(
+            column(t, "a", &accessor),
+            [
+                LiteralValue::BigInt(1),
+                LiteralValue::BigInt(3),
+                LiteralValue::BigInt(7),
+            ],
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("a", [1, 3, 7, 3]),
+        varchar("b", ["t", "jj", "f", "x"]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_negated_in_list_query_over_strings() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1, 2, 3, 4]),
+        varchar("b", ["t", "ghi", "jj", "f"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        not(in_list(
+            column(t, "b", &accessor),
+            [
+                LiteralValue::VarChar(("ghi".to_string(), "ghi".into())),
+                LiteralValue::VarChar(("f".to_string(), "f".into())),
+            ],
+        )),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("a", [1, 3])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_an_in_list_query_over_decimals_with_differently_scaled_values() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1, 2, 3, 4]),
+        decimal75("e", 10, 2, [100, 150, 200, 250]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        in_list(
+            column(t, "e", &accessor),
+            [
+                LiteralValue::BigInt(2),
+                LiteralValue::Decimal75(Precision::new(2).unwrap(), 1, Curve25519Scalar::from(15)),
+            ],
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("a", [2, 3])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_compute_the_correct_output_of_an_in_list_expr_using_result_evaluate() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([bigint("a", [1, 2, 3, 7, 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let in_list_expr: DynProofExpr<RistrettoPoint> = in_list(
+        column(t, "a", &accessor),
+        [
+            LiteralValue::BigInt(7),
+            LiteralValue::BigInt(0),
+            LiteralValue::BigInt(2),
+        ],
+    );
+    let alloc = Bump::new();
+    let res = in_list_expr.result_evaluate(5, &alloc, &accessor);
+    let expected_res = Column::Boolean(&[false, true, false, true, true]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_create_an_in_list_expr_with_no_values_or_mismatched_types() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([bigint("a", [1, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        DynProofExpr::<RistrettoPoint>::try_new_in_list(column(t, "a", &accessor), vec![]),
+        Err(ConversionError::InvalidExpression { .. })
+    ));
+    assert!(matches!(
+        DynProofExpr::<RistrettoPoint>::try_new_in_list(
+            column(t, "a", &accessor),
+            vec![LiteralValue::BigInt(1), LiteralValue::Boolean(true)]
+        ),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+}
@@ -72,8 +72,30 @@ use equals_expr::{
 #[cfg(all(test, feature = "blitzar"))]
 mod equals_expr_test;
 
+mod in_list_expr;
+use in_list_expr::InListExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod in_list_expr_test;
+
+mod mod_expr;
+use mod_expr::ModExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod mod_expr_test;
+
+mod case_expr;
+use case_expr::CaseExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod case_expr_test;
+
+mod overflow_expr;
+use overflow_expr::OverflowExpr;
+pub use overflow_expr::OverflowMode;
+#[cfg(all(test, feature = "blitzar"))]
+mod overflow_expr_test;
+
 mod sign_expr;
-use sign_expr::{count_sign, prover_evaluate_sign, result_evaluate_sign, verifier_evaluate_sign};
+use sign_expr::result_evaluate_sign;
+pub(crate) use sign_expr::{count_sign, prover_evaluate_sign, verifier_evaluate_sign};
 #[cfg(all(test, feature = "blitzar"))]
 mod sign_expr_test;
 
@@ -0,0 +1,198 @@
+use super::{count_sign, prover_evaluate_sign, verifier_evaluate_sign, DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        map::IndexSet,
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bumpalo::Bump;
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+
+/// Provable AST expression for `expr % modulus` with a constant, positive `modulus`
+///
+/// The remainder is never negative, so `-7 % 3` is `2`. The prover commits to the quotient `q`
+/// and the remainder `r` and proves that `expr = q * modulus + r`. Sign decompositions show that
+/// `0 <= r < modulus` and that `q` is small enough for the identity to hold over the integers
+/// rather than only in the scalar field.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModExpr<C: Commitment> {
+    expr: Box<DynProofExpr<C>>,
+    modulus: i64,
+}
+
+impl<C: Commitment> ModExpr<C> {
+    /// Create a new modulo expression
+    pub fn new(expr: Box<DynProofExpr<C>>, modulus: i64) -> Self {
+        Self { expr, modulus }
+    }
+
+    /// Split every value of `column` into a quotient and a nonnegative remainder
+    ///
+    /// # Panics
+    /// Panics if `column` is not an integer column, which the constructor rules out.
+    fn divide<'a>(
+        &self,
+        alloc: &'a Bump,
+        column: Column<'a, C::Scalar>,
+    ) -> (&'a [i128], &'a [i64]) {
+        let values = integer_values(column);
+        let modulus = i128::from(self.modulus);
+        let quotients = alloc.alloc_slice_fill_iter(values.iter().map(|x| x.div_euclid(modulus)));
+        let remainders = alloc.alloc_slice_fill_iter(values.iter().map(|x| {
+            i64::try_from(x.rem_euclid(modulus)).expect("remainders are less than the modulus")
+        }));
+        (quotients, remainders)
+    }
+}
+
+/// Widen the values of an integer column to `i128`
+///
+/// # Panics
+/// Panics if `column` is not an integer column, which the constructor rules out.
+fn integer_values<S: Scalar>(column: Column<'_, S>) -> Vec<i128> {
+    match column {
+        Column::TinyInt(col) => col.iter().copied().map(i128::from).collect(),
+        Column::SmallInt(col) => col.iter().copied().map(i128::from).collect(),
+        Column::Int(col) => col.iter().copied().map(i128::from).collect(),
+        Column::BigInt(col) => col.iter().copied().map(i128::from).collect(),
+        Column::Int128(col) => col.to_vec(),
+        _ => panic!("Modulo is only supported on integer columns"),
+    }
+}
+
+impl<C: Commitment> ProofExpr<C> for ModExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.expr.count(builder)?;
+        builder.count_intermediate_mles(2);
+        builder.count_subpolynomials(1);
+        builder.count_degree(2);
+        // 0 <= r, r < modulus and q is small
+        count_sign(builder)?;
+        count_sign(builder)?;
+        count_sign(builder)?;
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        ColumnType::BigInt
+    }
+
+    #[tracing::instrument(name = "ModExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let column = self.expr.result_evaluate(table_length, alloc, accessor);
+        let (_, remainders) = self.divide(alloc, column);
+        Column::BigInt(remainders)
+    }
+
+    #[tracing::instrument(name = "ModExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, C::Scalar>,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let column = self.expr.prover_evaluate(builder, alloc, accessor);
+        let (quotients, remainders) = self.divide(alloc, column);
+        builder.produce_intermediate_mle(remainders);
+        builder.produce_intermediate_mle(quotients);
+
+        // subpolynomial: expr - modulus * q - r
+        let modulus = C::Scalar::from(self.modulus);
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (C::Scalar::one(), vec![Box::new(column)]),
+                (-modulus, vec![Box::new(quotients)]),
+                (-C::Scalar::one(), vec![Box::new(remainders)]),
+            ],
+        );
+
+        // 0 <= r
+        let remainder_scalars =
+            alloc.alloc_slice_fill_iter(remainders.iter().map(|&r| C::Scalar::from(r)));
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            remainder_scalars,
+            #[cfg(test)]
+            false,
+        );
+
+        // r < modulus
+        let gaps = alloc.alloc_slice_fill_iter(
+            remainder_scalars
+                .iter()
+                .map(|&r| modulus - C::Scalar::one() - r),
+        );
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            gaps,
+            #[cfg(test)]
+            false,
+        );
+
+        // q is small
+        let quotient_scalars =
+            alloc.alloc_slice_fill_iter(quotients.iter().map(|&q| C::Scalar::from(q)));
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            quotient_scalars,
+            #[cfg(test)]
+            false,
+        );
+
+        Column::BigInt(remainders)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let one_eval = builder.mle_evaluations.input_one_evaluation;
+        let eval = self.expr.verifier_evaluate(builder, accessor)?;
+        let remainder_eval = builder.consume_intermediate_mle();
+        let quotient_eval = builder.consume_intermediate_mle();
+
+        // subpolynomial: expr - modulus * q - r
+        let modulus = C::Scalar::from(self.modulus);
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            eval - modulus * quotient_eval - remainder_eval,
+        );
+
+        // 0 <= r
+        let remainder_sign = verifier_evaluate_sign(builder, remainder_eval, one_eval)?;
+
+        // r < modulus
+        let gap_eval = (modulus - C::Scalar::one()) * one_eval - remainder_eval;
+        let gap_sign = verifier_evaluate_sign(builder, gap_eval, one_eval)?;
+
+        // q is small
+        verifier_evaluate_sign(builder, quotient_eval, one_eval)?;
+
+        if !remainder_sign.is_zero() || !gap_sign.is_zero() {
+            return Err(ProofError::VerificationError {
+                error: "remainder is outside of the range [0, modulus)",
+            });
+        }
+        Ok(remainder_eval)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
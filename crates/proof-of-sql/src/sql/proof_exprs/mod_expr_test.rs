@@ -0,0 +1,98 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, Column, OwnedTableTestAccessor},
+        scalar::Curve25519Scalar,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr, ProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use bumpalo::Bump;
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+// select a % 3 as r, b from sxt.t where b % 100 = 0
+#[test]
+fn we_can_prove_a_typical_modulo_query() {
+    let data = owned_table::<Curve25519Scalar>([
+        smallint("a", [-7_i16, 0, 5, 9, 2]),
+        bigint("b", [100_i64, 7, -200, 0, 99]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![
+            aliased_plan(modulo(column(t, "a", &accessor), 3), "r"),
+            col_expr_plan(t, "b", &accessor),
+        ],
+        tab(t),
+        equal(modulo(column(t, "b", &accessor), 100), const_bigint(0)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("r", [2, 2, 0]), bigint("b", [100, -200, 0])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_modulo_query_over_large_values() {
+    let data = owned_table::<Curve25519Scalar>([int128(
+        "a",
+        [i128::MIN, i128::MAX, -1, i128::from(i64::MAX)],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![aliased_plan(
+            modulo(column(t, "a", &accessor), i64::MAX),
+            "r",
+        )],
+        tab(t),
+        const_bool(true),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let modulus = i128::from(i64::MAX);
+    let expected_res = owned_table([bigint(
+        "r",
+        [i128::MIN, i128::MAX, -1, modulus].map(|x| i64::try_from(x.rem_euclid(modulus)).unwrap()),
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_compute_the_correct_output_of_a_modulo_expr_using_result_evaluate() {
+    let data = owned_table::<Curve25519Scalar>([int("a", [-4, -3, 0, 1, 8])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let mod_expr: DynProofExpr<RistrettoPoint> = modulo(column(t, "a", &accessor), 3);
+    let alloc = Bump::new();
+    let res = mod_expr.result_evaluate(5, &alloc, &accessor);
+    let expected_res = Column::BigInt(&[2, 0, 0, 1, 2]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_create_a_modulo_expr_with_a_nonpositive_modulus_or_a_non_integer_column() {
+    let data =
+        owned_table::<Curve25519Scalar>([bigint("a", [1, 2]), decimal75("d", 5, 2, [100, 200])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        DynProofExpr::<RistrettoPoint>::try_new_mod(column(t, "a", &accessor), 0),
+        Err(ConversionError::InvalidExpression { .. })
+    ));
+    assert!(matches!(
+        DynProofExpr::<RistrettoPoint>::try_new_mod(column(t, "a", &accessor), -3),
+        Err(ConversionError::InvalidExpression { .. })
+    ));
+    assert!(matches!(
+        DynProofExpr::<RistrettoPoint>::try_new_mod(column(t, "d", &accessor), 3),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+}
@@ -31,6 +31,11 @@ impl<C: Commitment> MultiplyExpr<C> {
     pub fn new(lhs: Box<DynProofExpr<C>>, rhs: Box<DynProofExpr<C>>) -> Self {
         Self { lhs, rhs }
     }
+
+    /// See [`DynProofExpr::magnitude_bits`]
+    pub(super) fn magnitude_bits(&self) -> Option<u32> {
+        Some(self.lhs.magnitude_bits()? + self.rhs.magnitude_bits()?)
+    }
 }
 
 impl<C: Commitment> ProofExpr<C> for MultiplyExpr<C> {
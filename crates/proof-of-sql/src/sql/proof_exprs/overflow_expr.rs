@@ -0,0 +1,367 @@
+use super::{count_sign, prover_evaluate_sign, verifier_evaluate_sign, DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        map::IndexSet,
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bumpalo::Bump;
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+
+/// How integer arithmetic handles a result outside of the range of its type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OverflowMode {
+    /// Fail verification with an overflow error, as arithmetic does by default
+    Reject,
+    /// Wrap the result around the range of the type, as two's complement arithmetic does
+    Wrap,
+    /// Clamp the result to the minimum or maximum of the type
+    Saturate,
+}
+
+/// Provable AST expression applying [`OverflowMode::Wrap`] or [`OverflowMode::Saturate`] to integer
+/// arithmetic
+///
+/// The inner expression is computed exactly in the scalar field, and the prover commits to the
+/// adjusted result `r`.
+/// - When wrapping, the prover also commits to the number `q` of times the exact value `s` wraps,
+///   and proves that `s = q * 2^bits + r`. Sign decompositions show that `r` is within the range of
+///   the type and that `q` is small enough for the identity to hold over the integers.
+/// - When saturating, sign decompositions give the indicators `o` of `s > MAX` and `u` of `s < MIN`,
+///   which are proven boolean, and the prover shows that `r = s + o * (MAX - s) + u * (MIN - s)`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OverflowExpr<C: Commitment> {
+    expr: Box<DynProofExpr<C>>,
+    mode: OverflowMode,
+}
+
+impl<C: Commitment> OverflowExpr<C> {
+    /// Create a new expression applying `mode` to `expr`
+    pub fn new(expr: Box<DynProofExpr<C>>, mode: OverflowMode) -> Self {
+        Self { expr, mode }
+    }
+
+    /// The minimum and maximum of the type and the number of bits it has
+    ///
+    /// # Errors
+    /// Returns an error if the type is not an integer type of at most 64 bits. The constructors of
+    /// [`DynProofExpr`] rule this out, but a deserialized plan may not have gone through them.
+    fn bounds(&self) -> Result<(i128, i128, u32), ProofError> {
+        let bits = match self.expr.data_type() {
+            ColumnType::TinyInt => 8,
+            ColumnType::SmallInt => 16,
+            ColumnType::Int => 32,
+            ColumnType::BigInt => 64,
+            _ => Err(ProofError::VerificationError {
+                error: "overflow modes are only supported on integer types of at most 64 bits",
+            })?,
+        };
+        Ok((-(1_i128 << (bits - 1)), (1_i128 << (bits - 1)) - 1, bits))
+    }
+
+    /// The bounds of the type when proving
+    ///
+    /// # Panics
+    /// Panics if the type is not an integer type of at most 64 bits, see [`Self::bounds`].
+    fn prover_bounds(&self) -> (i128, i128, u32) {
+        self.bounds()
+            .expect("Overflow modes are only supported on integer types of at most 64 bits")
+    }
+
+    /// Split every exact value into the number of times it wraps and the wrapped result
+    fn wrap(&self, values: &[i128]) -> (Vec<i128>, Vec<i128>) {
+        let (min, _, bits) = self.prover_bounds();
+        let modulus = 1_i128 << bits;
+        values
+            .iter()
+            .map(|x| {
+                (
+                    (x - min).div_euclid(modulus),
+                    (x - min).rem_euclid(modulus) + min,
+                )
+            })
+            .unzip()
+    }
+
+    /// Compute the result of every exact value
+    fn adjust(&self, values: &[i128]) -> Vec<i128> {
+        let (min, max, _) = self.prover_bounds();
+        match self.mode {
+            OverflowMode::Reject => values.to_vec(),
+            OverflowMode::Wrap => self.wrap(values).1,
+            OverflowMode::Saturate => values.iter().map(|x| (*x).clamp(min, max)).collect(),
+        }
+    }
+
+    /// Store integer values as a column of the type of the expression
+    ///
+    /// # Panics
+    /// Panics if a value is outside of the range of the type.
+    fn to_column<'a>(&self, alloc: &'a Bump, values: &[i128]) -> Column<'a, C::Scalar> {
+        match self.expr.data_type() {
+            ColumnType::TinyInt => Column::TinyInt(alloc.alloc_slice_fill_iter(narrow(values))),
+            ColumnType::SmallInt => Column::SmallInt(alloc.alloc_slice_fill_iter(narrow(values))),
+            ColumnType::Int => Column::Int(alloc.alloc_slice_fill_iter(narrow(values))),
+            _ => Column::BigInt(alloc.alloc_slice_fill_iter(narrow(values))),
+        }
+    }
+}
+
+/// Convert results to a narrower integer type
+///
+/// # Panics
+/// Panics if a value is outside of the range of `T`.
+fn narrow<T: TryFrom<i128>>(values: &[i128]) -> impl ExactSizeIterator<Item = T> + '_ {
+    values.iter().map(|&x| {
+        T::try_from(x).unwrap_or_else(|_| panic!("results are within the range of the type"))
+    })
+}
+
+/// Read the exact values of an integer expression
+///
+/// # Panics
+/// Panics if `column` is not an integer or scalar column, or holds a value that does not fit in
+/// an `i128`.
+fn exact_values<S: Scalar>(column: Column<'_, S>) -> Vec<i128> {
+    match column {
+        Column::TinyInt(col) => col.iter().copied().map(i128::from).collect(),
+        Column::SmallInt(col) => col.iter().copied().map(i128::from).collect(),
+        Column::Int(col) => col.iter().copied().map(i128::from).collect(),
+        Column::BigInt(col) => col.iter().copied().map(i128::from).collect(),
+        Column::Int128(col) => col.to_vec(),
+        Column::Scalar(col) => col
+            .iter()
+            .map(|x| {
+                (*x).try_into()
+                    .unwrap_or_else(|_| panic!("integer arithmetic fits in an i128"))
+            })
+            .collect(),
+        _ => panic!("Overflow modes are only supported on integer expressions"),
+    }
+}
+
+impl<C: Commitment> ProofExpr<C> for OverflowExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.expr.count(builder)?;
+        self.bounds()?;
+        match self.mode {
+            OverflowMode::Reject => {}
+            OverflowMode::Wrap => {
+                builder.count_intermediate_mles(2);
+                builder.count_subpolynomials(1);
+                builder.count_degree(2);
+                // MIN <= r, r <= MAX and q is small
+                count_sign(builder)?;
+                count_sign(builder)?;
+                count_sign(builder)?;
+            }
+            OverflowMode::Saturate => {
+                builder.count_intermediate_mles(1);
+                builder.count_subpolynomials(1);
+                builder.count_degree(3);
+                // s > MAX and s < MIN
+                count_sign(builder)?;
+                count_sign(builder)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        self.expr.data_type()
+    }
+
+    #[tracing::instrument(name = "OverflowExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let column = self.expr.result_evaluate(table_length, alloc, accessor);
+        self.to_column(alloc, &self.adjust(&exact_values(column)))
+    }
+
+    #[tracing::instrument(name = "OverflowExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, C::Scalar>,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let column = self.expr.prover_evaluate(builder, alloc, accessor);
+        let values = exact_values(column);
+        let (min, max, bits) = self.prover_bounds();
+        let min = C::Scalar::from(min);
+        let max = C::Scalar::from(max);
+        match self.mode {
+            OverflowMode::Reject => {}
+            OverflowMode::Wrap => {
+                let (quotients, results) = self.wrap(&values);
+                let results = alloc.alloc_slice_copy(&results);
+                let quotients = alloc.alloc_slice_copy(&quotients);
+                builder.produce_intermediate_mle(results as &[_]);
+                builder.produce_intermediate_mle(quotients as &[_]);
+
+                // subpolynomial: s - 2^bits * q - r
+                let modulus = C::Scalar::from(1_i128 << bits);
+                builder.produce_sumcheck_subpolynomial(
+                    SumcheckSubpolynomialType::Identity,
+                    vec![
+                        (C::Scalar::one(), vec![Box::new(column)]),
+                        (-modulus, vec![Box::new(quotients as &[_])]),
+                        (-C::Scalar::one(), vec![Box::new(results as &[_])]),
+                    ],
+                );
+
+                // MIN <= r
+                let result_scalars =
+                    alloc.alloc_slice_fill_iter(results.iter().map(|&r| C::Scalar::from(r)));
+                let lower_gaps =
+                    alloc.alloc_slice_fill_iter(result_scalars.iter().map(|&r| r - min));
+                prover_evaluate_sign(
+                    builder,
+                    alloc,
+                    lower_gaps,
+                    #[cfg(test)]
+                    false,
+                );
+
+                // r <= MAX
+                let upper_gaps =
+                    alloc.alloc_slice_fill_iter(result_scalars.iter().map(|&r| max - r));
+                prover_evaluate_sign(
+                    builder,
+                    alloc,
+                    upper_gaps,
+                    #[cfg(test)]
+                    false,
+                );
+
+                // q is small
+                let quotient_scalars =
+                    alloc.alloc_slice_fill_iter(quotients.iter().map(|&q| C::Scalar::from(q)));
+                prover_evaluate_sign(
+                    builder,
+                    alloc,
+                    quotient_scalars,
+                    #[cfg(test)]
+                    false,
+                );
+            }
+            OverflowMode::Saturate => {
+                let results = alloc.alloc_slice_copy(&self.adjust(&values));
+                builder.produce_intermediate_mle(results as &[_]);
+
+                // o: s > MAX
+                let value_scalars =
+                    alloc.alloc_slice_fill_iter(values.iter().map(|&x| C::Scalar::from(x)));
+                let upper_gaps =
+                    alloc.alloc_slice_fill_iter(value_scalars.iter().map(|&x| max - x));
+                let above = prover_evaluate_sign(
+                    builder,
+                    alloc,
+                    upper_gaps,
+                    #[cfg(test)]
+                    false,
+                );
+
+                // u: s < MIN
+                let lower_gaps =
+                    alloc.alloc_slice_fill_iter(value_scalars.iter().map(|&x| x - min));
+                let below = prover_evaluate_sign(
+                    builder,
+                    alloc,
+                    lower_gaps,
+                    #[cfg(test)]
+                    false,
+                );
+
+                // subpolynomial: r - s - o * (MAX - s) - u * (MIN - s)
+                builder.produce_sumcheck_subpolynomial(
+                    SumcheckSubpolynomialType::Identity,
+                    vec![
+                        (C::Scalar::one(), vec![Box::new(results as &[_])]),
+                        (-C::Scalar::one(), vec![Box::new(column)]),
+                        (-max, vec![Box::new(above)]),
+                        (C::Scalar::one(), vec![Box::new(above), Box::new(column)]),
+                        (-min, vec![Box::new(below)]),
+                        (C::Scalar::one(), vec![Box::new(below), Box::new(column)]),
+                    ],
+                );
+            }
+        }
+        self.to_column(alloc, &self.adjust(&values))
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let one_eval = builder.mle_evaluations.input_one_evaluation;
+        let eval = self.expr.verifier_evaluate(builder, accessor)?;
+        let (min, max, bits) = self.bounds()?;
+        let min = C::Scalar::from(min);
+        let max = C::Scalar::from(max);
+        match self.mode {
+            OverflowMode::Reject => Ok(eval),
+            OverflowMode::Wrap => {
+                let result_eval = builder.consume_intermediate_mle();
+                let quotient_eval = builder.consume_intermediate_mle();
+
+                // subpolynomial: s - 2^bits * q - r
+                let modulus = C::Scalar::from(1_i128 << bits);
+                builder.produce_sumcheck_subpolynomial_evaluation(
+                    &SumcheckSubpolynomialType::Identity,
+                    eval - modulus * quotient_eval - result_eval,
+                );
+
+                // MIN <= r
+                let lower_sign =
+                    verifier_evaluate_sign(builder, result_eval - min * one_eval, one_eval)?;
+
+                // r <= MAX
+                let upper_sign =
+                    verifier_evaluate_sign(builder, max * one_eval - result_eval, one_eval)?;
+
+                // q is small
+                verifier_evaluate_sign(builder, quotient_eval, one_eval)?;
+
+                if !lower_sign.is_zero() || !upper_sign.is_zero() {
+                    return Err(ProofError::VerificationError {
+                        error: "wrapped result is outside of the range of its type",
+                    });
+                }
+                Ok(result_eval)
+            }
+            OverflowMode::Saturate => {
+                let result_eval = builder.consume_intermediate_mle();
+
+                // o: s > MAX
+                let above_eval = verifier_evaluate_sign(builder, max * one_eval - eval, one_eval)?;
+
+                // u: s < MIN
+                let below_eval = verifier_evaluate_sign(builder, eval - min * one_eval, one_eval)?;
+
+                // subpolynomial: r - s - o * (MAX - s) - u * (MIN - s)
+                builder.produce_sumcheck_subpolynomial_evaluation(
+                    &SumcheckSubpolynomialType::Identity,
+                    result_eval - eval - max * above_eval + above_eval * eval - min * below_eval
+                        + below_eval * eval,
+                );
+                Ok(result_eval)
+            }
+        }
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
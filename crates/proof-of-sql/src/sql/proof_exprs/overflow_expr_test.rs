@@ -0,0 +1,236 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, Column, OwnedTableTestAccessor},
+        proof::ProofError,
+        scalar::Curve25519Scalar,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, QueryError, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr, OverflowExpr, OverflowMode, ProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use bumpalo::Bump;
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+// select a + b as s, a - b as d, a * b as p from sxt.t, wrapping on overflow
+#[test]
+fn we_can_prove_a_query_with_wrapping_arithmetic() {
+    let data = owned_table::<Curve25519Scalar>([
+        tinyint("a", [i8::MAX, i8::MIN, -3, 100, 0]),
+        tinyint("b", [1_i8, -1, 4, 100, i8::MIN]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let a = || column(t, "a", &accessor);
+    let b = || column(t, "b", &accessor);
+    let ast = filter(
+        vec![
+            aliased_plan(add_with_overflow_mode(a(), b(), OverflowMode::Wrap), "s"),
+            aliased_plan(
+                subtract_with_overflow_mode(a(), b(), OverflowMode::Wrap),
+                "d",
+            ),
+            aliased_plan(
+                multiply_with_overflow_mode(a(), b(), OverflowMode::Wrap),
+                "p",
+            ),
+        ],
+        tab(t),
+        const_bool(true),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        tinyint("s", [i8::MIN, i8::MAX, 1, -56, i8::MIN]),
+        tinyint("d", [126_i8, i8::MIN + 1, -7, 0, i8::MIN]),
+        tinyint("p", [i8::MAX, i8::MIN, -12, 16, 0]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// select a + b as s, a - b as d, a * b as p from sxt.t, saturating on overflow
+#[test]
+fn we_can_prove_a_query_with_saturating_arithmetic() {
+    let data = owned_table::<Curve25519Scalar>([
+        bigint("a", [i64::MAX, i64::MIN, -3, i64::MAX, 0]),
+        bigint("b", [1_i64, 1, 4, i64::MAX, i64::MIN]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let a = || column(t, "a", &accessor);
+    let b = || column(t, "b", &accessor);
+    let ast = filter(
+        vec![
+            aliased_plan(
+                add_with_overflow_mode(a(), b(), OverflowMode::Saturate),
+                "s",
+            ),
+            aliased_plan(
+                subtract_with_overflow_mode(a(), b(), OverflowMode::Saturate),
+                "d",
+            ),
+            aliased_plan(
+                multiply_with_overflow_mode(a(), b(), OverflowMode::Saturate),
+                "p",
+            ),
+        ],
+        tab(t),
+        const_bool(true),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("s", [i64::MAX, i64::MIN + 1, 1, i64::MAX, i64::MIN]),
+        bigint("d", [i64::MAX - 1, i64::MIN, -7, 0, i64::MAX]),
+        bigint("p", [i64::MAX, i64::MIN, -12, i64::MAX, 0]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_compute_the_correct_output_of_an_overflow_expr_using_result_evaluate() {
+    let data = owned_table::<Curve25519Scalar>([
+        smallint("a", [i16::MAX, i16::MIN, 5]),
+        smallint("b", [2_i16, -2, 5]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let alloc = Bump::new();
+    let wrap_expr: DynProofExpr<RistrettoPoint> = add_with_overflow_mode(
+        column(t, "a", &accessor),
+        column(t, "b", &accessor),
+        OverflowMode::Wrap,
+    );
+    let res = wrap_expr.result_evaluate(3, &alloc, &accessor);
+    assert_eq!(res, Column::SmallInt(&[i16::MIN + 1, i16::MAX - 1, 10]));
+    let saturate_expr: DynProofExpr<RistrettoPoint> = add_with_overflow_mode(
+        column(t, "a", &accessor),
+        column(t, "b", &accessor),
+        OverflowMode::Saturate,
+    );
+    let res = saturate_expr.result_evaluate(3, &alloc, &accessor);
+    assert_eq!(res, Column::SmallInt(&[i16::MAX, i16::MIN, 10]));
+}
+
+#[test]
+fn we_cannot_wrap_or_saturate_arithmetic_on_wide_or_decimal_columns() {
+    let data = owned_table::<Curve25519Scalar>([
+        int128("a", [1, 2]),
+        decimal75("d", 5, 2, [100, 200]),
+        bigint("b", [1, 2]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        DynProofExpr::<RistrettoPoint>::try_new_add_with_overflow_mode(
+            column(t, "a", &accessor),
+            column(t, "b", &accessor),
+            OverflowMode::Wrap
+        ),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+    assert!(matches!(
+        DynProofExpr::<RistrettoPoint>::try_new_multiply_with_overflow_mode(
+            column(t, "d", &accessor),
+            column(t, "b", &accessor),
+            OverflowMode::Saturate
+        ),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+    assert!(
+        DynProofExpr::<RistrettoPoint>::try_new_add_with_overflow_mode(
+            column(t, "a", &accessor),
+            column(t, "b", &accessor),
+            OverflowMode::Reject
+        )
+        .is_ok()
+    );
+}
+
+#[test]
+fn we_cannot_wrap_or_saturate_arithmetic_whose_exact_value_may_not_fit_in_an_i128() {
+    let data = owned_table::<Curve25519Scalar>([bigint("a", [1, 2]), bigint("b", [3, 4])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let a = || column::<RistrettoPoint>(t, "a", &accessor);
+    // a * a * a can be as large as 2^189
+    assert!(matches!(
+        DynProofExpr::try_new_multiply_with_overflow_mode(
+            multiply(a(), a()),
+            a(),
+            OverflowMode::Wrap
+        ),
+        Err(ConversionError::Unprovable { .. })
+    ));
+    // a * a + b can be as large as 2^127
+    assert!(matches!(
+        DynProofExpr::try_new_add_with_overflow_mode(
+            multiply(a(), a()),
+            column(t, "b", &accessor),
+            OverflowMode::Saturate
+        ),
+        Err(ConversionError::Unprovable { .. })
+    ));
+    // a * a fits, and so does a product of wrapped values
+    let wrapped =
+        || DynProofExpr::try_new_multiply_with_overflow_mode(a(), a(), OverflowMode::Wrap).unwrap();
+    assert!(DynProofExpr::try_new_multiply_with_overflow_mode(
+        wrapped(),
+        wrapped(),
+        OverflowMode::Wrap
+    )
+    .is_ok());
+}
+
+// A deserialized plan has not gone through the constructors, so the verifier must reject an
+// overflow mode on a non-integer type instead of panicking
+#[test]
+fn we_cannot_verify_a_deserialized_overflow_expr_on_a_non_integer_type() {
+    let data = owned_table::<Curve25519Scalar>([
+        tinyint("a", [1_i8, 2]),
+        decimal75("d", 5, 2, [100, 200]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![aliased_plan(
+            add_with_overflow_mode(
+                column(t, "a", &accessor),
+                column(t, "a", &accessor),
+                OverflowMode::Wrap,
+            ),
+            "s",
+        )],
+        tab(t),
+        const_bool(true),
+    );
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    for mode in [
+        OverflowMode::Reject,
+        OverflowMode::Wrap,
+        OverflowMode::Saturate,
+    ] {
+        let invalid_ast = filter(
+            vec![aliased_plan(
+                DynProofExpr::Overflow(OverflowExpr::new(
+                    Box::new(column(t, "d", &accessor)),
+                    mode,
+                )),
+                "s",
+            )],
+            tab(t),
+            const_bool(true),
+        );
+        assert!(matches!(
+            verifiable_res.verify(&invalid_ast, &accessor, &()),
+            Err(QueryError::ProofError {
+                source: ProofError::VerificationError { .. }
+            })
+        ));
+    }
+}
@@ -16,7 +16,7 @@ fn prover_evaluation_generates_the_bit_distribution_of_a_constant_column() {
     let dist = BitDistribution::new::<Curve25519Scalar, _>(&data);
     let alloc = Bump::new();
     let data: Vec<Curve25519Scalar> = data.into_iter().map(Curve25519Scalar::from).collect();
-    let mut builder = FinalRoundBuilder::new(3, 2, Vec::new());
+    let mut builder = FinalRoundBuilder::new(3, 3, 2, Vec::new());
     let sign = prover_evaluate_sign(&mut builder, &alloc, &data, false);
     assert_eq!(sign, [false; 3]);
     assert_eq!(builder.bit_distributions(), [dist]);
@@ -28,7 +28,7 @@ fn prover_evaluation_generates_the_bit_distribution_of_a_negative_constant_colum
     let dist = BitDistribution::new::<Curve25519Scalar, _>(&data);
     let alloc = Bump::new();
     let data: Vec<Curve25519Scalar> = data.into_iter().map(Curve25519Scalar::from).collect();
-    let mut builder = FinalRoundBuilder::new(3, 2, Vec::new());
+    let mut builder = FinalRoundBuilder::new(3, 3, 2, Vec::new());
     let sign = prover_evaluate_sign(&mut builder, &alloc, &data, false);
     assert_eq!(sign, [true; 3]);
     assert_eq!(builder.bit_distributions(), [dist]);
@@ -63,6 +63,7 @@ fn we_can_verify_a_constant_decomposition() {
         &evaluation_point,
         &sumcheck_random_scalars,
         &[],
+        &[],
     );
     let one_eval = sumcheck_evaluations.input_one_evaluation;
 
@@ -87,6 +88,7 @@ fn verification_of_constant_data_fails_if_the_commitment_doesnt_match_the_bit_di
         &evaluation_point,
         &sumcheck_random_scalars,
         &[],
+        &[],
     );
     let one_eval = sumcheck_evaluations.input_one_evaluation;
 
@@ -1,4 +1,4 @@
-use super::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, TableExpr};
+use super::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, OverflowMode, TableExpr};
 use crate::base::{
     commitment::Commitment,
     database::{ColumnRef, LiteralValue, SchemaAccessor, TableRef},
@@ -36,6 +36,16 @@ pub fn equal<C: Commitment>(left: DynProofExpr<C>, right: DynProofExpr<C>) -> Dy
     DynProofExpr::try_new_equals(left, right).unwrap()
 }
 
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_in_list()` returns an error.
+pub fn in_list<C: Commitment>(
+    expr: DynProofExpr<C>,
+    list: impl IntoIterator<Item = LiteralValue<C::Scalar>>,
+) -> DynProofExpr<C> {
+    DynProofExpr::try_new_in_list(expr, list.into_iter().collect()).unwrap()
+}
+
 /// # Panics
 /// Panics if:
 /// - `DynProofExpr::try_new_inequality()` returns an error.
@@ -92,6 +102,57 @@ pub fn multiply<C: Commitment>(left: DynProofExpr<C>, right: DynProofExpr<C>) ->
     DynProofExpr::try_new_multiply(left, right).unwrap()
 }
 
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_case()` returns an error.
+pub fn case_when<C: Commitment>(
+    predicate: DynProofExpr<C>,
+    then: DynProofExpr<C>,
+    otherwise: DynProofExpr<C>,
+) -> DynProofExpr<C> {
+    DynProofExpr::try_new_case(predicate, then, otherwise).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_add_with_overflow_mode()` returns an error.
+pub fn add_with_overflow_mode<C: Commitment>(
+    left: DynProofExpr<C>,
+    right: DynProofExpr<C>,
+    mode: OverflowMode,
+) -> DynProofExpr<C> {
+    DynProofExpr::try_new_add_with_overflow_mode(left, right, mode).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_subtract_with_overflow_mode()` returns an error.
+pub fn subtract_with_overflow_mode<C: Commitment>(
+    left: DynProofExpr<C>,
+    right: DynProofExpr<C>,
+    mode: OverflowMode,
+) -> DynProofExpr<C> {
+    DynProofExpr::try_new_subtract_with_overflow_mode(left, right, mode).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_multiply_with_overflow_mode()` returns an error.
+pub fn multiply_with_overflow_mode<C: Commitment>(
+    left: DynProofExpr<C>,
+    right: DynProofExpr<C>,
+    mode: OverflowMode,
+) -> DynProofExpr<C> {
+    DynProofExpr::try_new_multiply_with_overflow_mode(left, right, mode).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_mod()` returns an error.
+pub fn modulo<C: Commitment>(expr: DynProofExpr<C>, modulus: i64) -> DynProofExpr<C> {
+    DynProofExpr::try_new_mod(expr, modulus).unwrap()
+}
+
 pub fn const_bool<C: Commitment>(val: bool) -> DynProofExpr<C> {
     DynProofExpr::new_literal(LiteralValue::Boolean(val))
 }
@@ -235,3 +296,23 @@ pub fn sum_expr<C: Commitment>(expr: DynProofExpr<C>, alias: &str) -> AliasedDyn
         alias: alias.parse().unwrap(),
     }
 }
+
+/// # Panics
+/// Panics if:
+/// - `alias.parse()` fails to parse the provided alias string.
+pub fn max_expr<C: Commitment>(expr: DynProofExpr<C>, alias: &str) -> AliasedDynProofExpr<C> {
+    AliasedDynProofExpr {
+        expr: DynProofExpr::new_aggregate(AggregationOperator::Max, expr),
+        alias: alias.parse().unwrap(),
+    }
+}
+
+/// # Panics
+/// Panics if:
+/// - `alias.parse()` fails to parse the provided alias string.
+pub fn min_expr<C: Commitment>(expr: DynProofExpr<C>, alias: &str) -> AliasedDynProofExpr<C> {
+    AliasedDynProofExpr {
+        expr: DynProofExpr::new_aggregate(AggregationOperator::Min, expr),
+        alias: alias.parse().unwrap(),
+    }
+}
@@ -0,0 +1,138 @@
+use super::DynProofPlan;
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            Column, ColumnField, ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor,
+            OwnedTable, TableRef,
+        },
+        map::IndexSet,
+        proof::ProofError,
+    },
+    sql::proof::{
+        CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
+        VerificationBuilder,
+    },
+};
+use alloc::{boxed::Box, vec::Vec};
+use bumpalo::Bump;
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT <column1> AS <alias1>, ..., <columnN> AS <aliasN> FROM (<input>)
+/// ```
+///
+/// The result columns are exactly those of `input`, only named differently, so renaming
+/// them adds no intermediate MLEs, commitments, or constraints to the proof.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AliasExec<C: Commitment> {
+    pub(super) input: Box<DynProofPlan<C>>,
+    pub(super) aliases: Vec<Identifier>,
+}
+
+impl<C: Commitment> AliasExec<C> {
+    /// Creates a new alias expression, renaming the result columns of `input` to `aliases` in order.
+    pub fn new(input: DynProofPlan<C>, aliases: Vec<Identifier>) -> Self {
+        Self {
+            input: Box::new(input),
+            aliases,
+        }
+    }
+}
+
+impl<C: Commitment> ProofPlan<C> for AliasExec<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        if self.aliases.len() != self.input.get_column_result_fields().len() {
+            Err(ProofError::VerificationError {
+                error: "number of aliases does not match the number of input columns",
+            })?;
+        }
+        self.input.count(builder, accessor)
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        self.input.get_length(accessor)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        self.input.get_offset(accessor)
+    }
+
+    fn max_output_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        self.input.max_output_length(accessor)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<Vec<C::Scalar>, ProofError> {
+        // The input may look up its result columns by name, so give them back their original names.
+        let input_result = result
+            .map(|table| {
+                OwnedTable::try_from_iter(
+                    self.input
+                        .get_column_result_fields()
+                        .iter()
+                        .map(ColumnField::name)
+                        .zip(table.inner_table().values().cloned()),
+                )
+            })
+            .transpose()
+            .map_err(|_| ProofError::VerificationError {
+                error: "result columns have different lengths",
+            })?;
+        self.input
+            .verifier_evaluate(builder, accessor, input_result.as_ref())
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        self.input
+            .get_column_result_fields()
+            .iter()
+            .zip(&self.aliases)
+            .map(|(field, alias)| ColumnField::new(*alias, field.data_type()))
+            .collect()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        self.input.get_column_references()
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        self.input.get_table_references()
+    }
+}
+
+impl<C: Commitment> ProverEvaluate<C::Scalar> for AliasExec<C> {
+    #[tracing::instrument(name = "AliasExec::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        input_length: usize,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Vec<Column<'a, C::Scalar>> {
+        self.input.result_evaluate(input_length, alloc, accessor)
+    }
+
+    fn first_round_evaluate(&self, builder: &mut FirstRoundBuilder) {
+        self.input.first_round_evaluate(builder);
+    }
+
+    #[tracing::instrument(name = "AliasExec::final_round_evaluate", level = "debug", skip_all)]
+    fn final_round_evaluate<'a>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, C::Scalar>,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Vec<Column<'a, C::Scalar>> {
+        self.input.final_round_evaluate(builder, alloc, accessor)
+    }
+}
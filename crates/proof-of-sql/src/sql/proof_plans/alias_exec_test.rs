@@ -0,0 +1,117 @@
+use super::{test_utility::*, DynProofPlan};
+use crate::{
+    base::{
+        database::{
+            owned_table_utility::*, ColumnField, ColumnType, OwnedTableTestAccessor, TestAccessor,
+        },
+        proof::ProofError,
+    },
+    sql::{
+        proof::{exercise_verification, ProofPlan, QueryError, QueryProof, VerifiableQueryResult},
+        proof_exprs::test_utility::*,
+    },
+};
+use blitzar::proof::InnerProductProof;
+use curve25519_dalek::RistrettoPoint;
+
+#[test]
+fn we_can_rename_the_result_columns_of_a_plan() {
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let t = "sxt.t".parse().unwrap();
+    accessor.add_table(
+        t,
+        owned_table([bigint("a", [1, 2]), varchar("b", ["x", "y"])]),
+        0,
+    );
+    let expr: DynProofPlan<RistrettoPoint> = alias(
+        projection(cols_expr_plan(t, &["a", "b"], &accessor), tab(t)),
+        &["value", "label"],
+    );
+    assert_eq!(
+        expr.get_column_result_fields(),
+        vec![
+            ColumnField::new("value".parse().unwrap(), ColumnType::BigInt),
+            ColumnField::new("label".parse().unwrap(), ColumnType::VarChar),
+        ]
+    );
+}
+
+#[test]
+fn we_can_prove_an_alias_without_extra_commitments() {
+    let data = owned_table([
+        bigint("a", [101, 104, 105, 102, 105]),
+        bigint("b", [1, 2, 3, 4, 7]),
+        varchar("d", ["1", "2", "3", "4", "5"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let input = || {
+        filter(
+            cols_expr_plan(t, &["b", "d"], &accessor),
+            tab(t),
+            equal(column(t, "a", &accessor), const_int128(105)),
+        )
+    };
+    let expr = alias(input(), &["value", "label"]);
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([bigint("value", [3, 7]), varchar("label", ["3", "5"])]);
+    assert_eq!(res, expected);
+
+    let (aliased_proof, _) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let (proof, _) = QueryProof::<InnerProductProof>::new(&input(), &accessor, &());
+    assert_eq!(aliased_proof.summary(), proof.summary());
+}
+
+/// `select a as k, sum(c) as total, count(*) as n from sxt.t where b = 99 group by a`
+#[test]
+fn we_can_prove_an_alias_of_a_group_by() {
+    let data = owned_table([
+        bigint("a", [1, 2, 2, 1, 2]),
+        bigint("b", [99, 99, 99, 99, 0]),
+        bigint("c", [101, 102, 103, 104, 105]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = alias(
+        group_by(
+            cols_expr(t, &["a"], &accessor),
+            vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+            "__count__",
+            tab(t),
+            equal(column(t, "b", &accessor), const_int128(99)),
+        ),
+        &["k", "total", "n"],
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("k", [1, 2]),
+        bigint("total", [101 + 104, 102 + 103]),
+        bigint("n", [2, 2]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn we_cannot_verify_an_alias_with_the_wrong_number_of_aliases() {
+    let data = owned_table([bigint("a", [1, 2]), bigint("b", [3, 4])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr: DynProofPlan<RistrettoPoint> = alias(
+        projection(cols_expr_plan(t, &["a", "b"], &accessor), tab(t)),
+        &["value"],
+    );
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(matches!(
+        res.verify(&expr, &accessor, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::VerificationError { .. }
+        })
+    ));
+}
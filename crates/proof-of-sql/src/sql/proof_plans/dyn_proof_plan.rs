@@ -1,4 +1,4 @@
-use super::{FilterExec, GroupByExec, ProjectionExec};
+use super::{AliasExec, FilterExec, GroupByExec, ProjectionExec};
 use crate::{
     base::{
         commitment::Commitment,
@@ -41,6 +41,11 @@ pub enum DynProofPlan<C: Commitment> {
     ///     SELECT <result_expr1>, ..., <result_exprN> FROM <table> WHERE <where_clause>
     /// ```
     Filter(FilterExec<C>),
+    /// Provable expressions for queries of the form, where the result columns of `input` are renamed
+    /// ```ignore
+    ///     SELECT <column1> AS <alias1>, ..., <columnN> AS <aliasN> FROM (<input>)
+    /// ```
+    Alias(AliasExec<C>),
 }
 
 impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
@@ -53,6 +58,7 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.count(builder, accessor),
             DynProofPlan::GroupBy(expr) => expr.count(builder, accessor),
             DynProofPlan::Filter(expr) => expr.count(builder, accessor),
+            DynProofPlan::Alias(expr) => expr.count(builder, accessor),
         }
     }
 
@@ -61,6 +67,7 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.get_length(accessor),
             DynProofPlan::GroupBy(expr) => expr.get_length(accessor),
             DynProofPlan::Filter(expr) => expr.get_length(accessor),
+            DynProofPlan::Alias(expr) => expr.get_length(accessor),
         }
     }
 
@@ -69,6 +76,16 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.get_offset(accessor),
             DynProofPlan::GroupBy(expr) => expr.get_offset(accessor),
             DynProofPlan::Filter(expr) => expr.get_offset(accessor),
+            DynProofPlan::Alias(expr) => expr.get_offset(accessor),
+        }
+    }
+
+    fn max_output_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        match self {
+            DynProofPlan::Projection(expr) => expr.max_output_length(accessor),
+            DynProofPlan::GroupBy(expr) => expr.max_output_length(accessor),
+            DynProofPlan::Filter(expr) => expr.max_output_length(accessor),
+            DynProofPlan::Alias(expr) => expr.max_output_length(accessor),
         }
     }
 
@@ -83,6 +100,7 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.verifier_evaluate(builder, accessor, result),
             DynProofPlan::GroupBy(expr) => expr.verifier_evaluate(builder, accessor, result),
             DynProofPlan::Filter(expr) => expr.verifier_evaluate(builder, accessor, result),
+            DynProofPlan::Alias(expr) => expr.verifier_evaluate(builder, accessor, result),
         }
     }
 
@@ -91,6 +109,7 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.get_column_result_fields(),
             DynProofPlan::GroupBy(expr) => expr.get_column_result_fields(),
             DynProofPlan::Filter(expr) => expr.get_column_result_fields(),
+            DynProofPlan::Alias(expr) => expr.get_column_result_fields(),
         }
     }
 
@@ -99,6 +118,7 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.get_column_references(),
             DynProofPlan::GroupBy(expr) => expr.get_column_references(),
             DynProofPlan::Filter(expr) => expr.get_column_references(),
+            DynProofPlan::Alias(expr) => expr.get_column_references(),
         }
     }
 
@@ -107,6 +127,7 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.get_table_references(),
             DynProofPlan::GroupBy(expr) => expr.get_table_references(),
             DynProofPlan::Filter(expr) => expr.get_table_references(),
+            DynProofPlan::Alias(expr) => expr.get_table_references(),
         }
     }
 }
@@ -123,6 +144,7 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.result_evaluate(input_length, alloc, accessor),
             DynProofPlan::GroupBy(expr) => expr.result_evaluate(input_length, alloc, accessor),
             DynProofPlan::Filter(expr) => expr.result_evaluate(input_length, alloc, accessor),
+            DynProofPlan::Alias(expr) => expr.result_evaluate(input_length, alloc, accessor),
         }
     }
 
@@ -131,6 +153,7 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.first_round_evaluate(builder),
             DynProofPlan::GroupBy(expr) => expr.first_round_evaluate(builder),
             DynProofPlan::Filter(expr) => expr.first_round_evaluate(builder),
+            DynProofPlan::Alias(expr) => expr.first_round_evaluate(builder),
         }
     }
 
@@ -145,6 +168,7 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.final_round_evaluate(builder, alloc, accessor),
             DynProofPlan::GroupBy(expr) => expr.final_round_evaluate(builder, alloc, accessor),
             DynProofPlan::Filter(expr) => expr.final_round_evaluate(builder, alloc, accessor),
+            DynProofPlan::Alias(expr) => expr.final_round_evaluate(builder, alloc, accessor),
         }
     }
 }
@@ -31,6 +31,9 @@ use serde::{Deserialize, Serialize};
 /// ```
 ///
 /// This differs from the [`FilterExec`] in that the result is not a sparse table.
+///
+/// The result columns are not committed to, since the verifier evaluates them from the result
+/// itself. Each result column only adds the opening of its input columns to the proof.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct OstensibleFilterExec<C: Commitment, H: ProverHonestyMarker> {
     pub(super) aliased_results: Vec<AliasedDynProofExpr<C>>,
@@ -68,7 +71,6 @@ where
         self.where_clause.count(builder)?;
         for aliased_expr in &self.aliased_results {
             aliased_expr.expr.count(builder)?;
-            builder.count_intermediate_mles(1);
         }
         builder.count_intermediate_mles(2);
         builder.count_subpolynomials(3);
@@ -85,6 +87,11 @@ where
         accessor.get_offset(self.table.table_ref)
     }
 
+    /// Every output row is one of the input rows
+    fn max_output_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        self.get_length(accessor)
+    }
+
     #[allow(unused_variables)]
     fn verifier_evaluate(
         &self,
@@ -101,8 +108,8 @@ where
                 .map(|aliased_expr| aliased_expr.expr.verifier_evaluate(builder, accessor))
                 .collect::<Result<Vec<_>, _>>()?,
         );
-        // 3. filtered_columns
-        let filtered_columns_evals: Vec<_> = repeat_with(|| builder.consume_intermediate_mle())
+        // 3. filtered_columns, which are the result columns
+        let filtered_columns_evals: Vec<_> = repeat_with(|| builder.consume_result_mle())
             .take(self.aliased_results.len())
             .collect();
         assert!(filtered_columns_evals.len() == self.aliased_results.len());
@@ -207,10 +214,6 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for FilterExec<C> {
             .collect();
         // Compute filtered_columns
         let (filtered_columns, result_len) = filter_columns(alloc, &columns, selection);
-        // 3. Produce MLEs
-        filtered_columns.iter().copied().for_each(|column| {
-            builder.produce_intermediate_mle(column);
-        });
 
         let alpha = builder.consume_post_result_challenge();
         let beta = builder.consume_post_result_challenge();
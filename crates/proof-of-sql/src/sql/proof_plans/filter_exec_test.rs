@@ -12,7 +12,7 @@ use crate::{
     sql::{
         proof::{
             exercise_verification, FirstRoundBuilder, ProofPlan, ProvableQueryResult,
-            ProverEvaluate, VerifiableQueryResult,
+            ProverEvaluate, QueryProof, VerifiableQueryResult,
         },
         proof_exprs::{test_utility::*, ColumnExpr, DynProofExpr, LiteralExpr, TableExpr},
     },
@@ -374,6 +374,22 @@ fn we_can_prove_a_filter_on_an_empty_table() {
     assert_eq!(res, expected);
 }
 
+#[test]
+fn we_can_prove_a_filter_on_an_empty_table_with_a_query_proof() {
+    let data = owned_table([bigint("a", [101; 0]), varchar("b", ["3"; 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = filter(
+        cols_expr_plan(t, &["b"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_int128(106)),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let res = proof.verify(&expr, &accessor, &result, &()).unwrap().table;
+    assert_eq!(res, owned_table([varchar("b", ["3"; 0])]));
+}
+
 #[test]
 fn we_can_prove_a_filter_with_empty_results() {
     let data = owned_table([
@@ -443,3 +459,49 @@ fn we_can_prove_a_filter() {
     ]);
     assert_eq!(res, expected);
 }
+
+// With one or two rows the proof has a single sumcheck variable, the smallest size it supports.
+fn prove_a_filter_on_a_table_with_a_single_sumcheck_variable(a: &[i64], d: &[&str], offset: usize) {
+    let data = owned_table([bigint("a", a.to_vec()), varchar("d", d.to_vec())]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, offset);
+    let expr = filter(
+        cols_expr_plan(t, &["a", "d"], &accessor),
+        tab(t),
+        gte(column(t, "a", &accessor), const_bigint(102)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let (expected_a, expected_d): (Vec<_>, Vec<_>) = a
+        .iter()
+        .zip(d)
+        .filter(|(a, _)| **a >= 102)
+        .map(|(&a, &d)| (a, d))
+        .unzip();
+    let expected = owned_table([bigint("a", expected_a), varchar("d", expected_d)]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn we_can_prove_a_filter_on_a_two_row_table() {
+    prove_a_filter_on_a_table_with_a_single_sumcheck_variable(&[105, 101], &["x", "y"], 0);
+    prove_a_filter_on_a_table_with_a_single_sumcheck_variable(&[101, 105], &["x", "y"], 0);
+    prove_a_filter_on_a_table_with_a_single_sumcheck_variable(&[105, 103], &["x", "y"], 0);
+    prove_a_filter_on_a_table_with_a_single_sumcheck_variable(&[101, 100], &["x", "y"], 0);
+}
+
+#[test]
+fn we_can_prove_a_filter_on_a_two_row_table_with_a_non_zero_offset() {
+    prove_a_filter_on_a_table_with_a_single_sumcheck_variable(&[105, 101], &["x", "y"], 3);
+    prove_a_filter_on_a_table_with_a_single_sumcheck_variable(&[101, 105], &["x", "y"], 3);
+}
+
+#[test]
+fn we_can_prove_a_filter_on_a_one_row_table() {
+    prove_a_filter_on_a_table_with_a_single_sumcheck_variable(&[105], &["x"], 0);
+    prove_a_filter_on_a_table_with_a_single_sumcheck_variable(&[101], &["x"], 0);
+    prove_a_filter_on_a_table_with_a_single_sumcheck_variable(&[105], &["x"], 3);
+    prove_a_filter_on_a_table_with_a_single_sumcheck_variable(&[101], &["x"], 3);
+}
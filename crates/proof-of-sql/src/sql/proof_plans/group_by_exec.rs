@@ -3,11 +3,13 @@ use crate::{
     base::{
         commitment::Commitment,
         database::{
+            filter_util::filter_column_by_index,
             group_by_util::{
-                aggregate_columns, compare_indexes_by_owned_columns, AggregatedColumns,
+                aggregate_columns, compare_indexes_by_columns, compare_indexes_by_owned_columns,
+                AggregatedColumns,
             },
             Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor,
-            MetadataAccessor, OwnedTable, TableRef,
+            LiteralValue, MetadataAccessor, OwnedColumn, OwnedTable, TableRef,
         },
         map::IndexSet,
         proof::ProofError,
@@ -19,13 +21,16 @@ use crate::{
             CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
             SumcheckSubpolynomialType, VerificationBuilder,
         },
-        proof_exprs::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, ProofExpr, TableExpr},
+        proof_exprs::{
+            count_sign, prover_evaluate_sign, verifier_evaluate_sign, AliasedDynProofExpr,
+            ColumnExpr, DynProofExpr, ProofExpr, TableExpr,
+        },
     },
 };
-use alloc::{boxed::Box, vec, vec::Vec};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
 use bumpalo::Bump;
 use core::{iter, iter::repeat_with};
-use num_traits::One;
+use num_traits::{One, Zero};
 use proof_of_sql_parser::Identifier;
 use serde::{Deserialize, Serialize};
 
@@ -33,17 +38,25 @@ use serde::{Deserialize, Serialize};
 /// ```ignore
 ///     SELECT <group_by_expr1>, ..., <group_by_exprM>,
 ///         SUM(<sum_expr1>.expr) as <sum_expr1>.alias, ..., SUM(<sum_exprN>.expr) as <sum_exprN>.alias,
+///         MAX(<max_expr1>.expr) as <max_expr1>.alias, ..., MAX(<max_exprP>.expr) as <max_exprP>.alias,
+///         MIN(<min_expr1>.expr) as <min_expr1>.alias, ..., MIN(<min_exprQ>.expr) as <min_exprQ>.alias,
 ///         COUNT(*) as count_alias
 ///     FROM <table>
 ///     WHERE <where_clause>
 ///     GROUP BY <group_by_expr1>, ..., <group_by_exprM>
 /// ```
 ///
-/// Note: if `group_by_exprs` is empty, then the query is equivalent to removing the `GROUP BY` clause.
+/// Note: if `group_by_exprs` is empty, then the query is equivalent to removing the `GROUP BY` clause,
+/// and the result has exactly one row even when no rows are selected. Since `NULL` is not supported,
+/// `SUM`, `MAX` and `MIN` over no rows are zero in that row.
+///
+/// Otherwise groups only exist for selected rows, so when no rows are selected the result has no rows.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct GroupByExec<C: Commitment> {
     pub(super) group_by_exprs: Vec<ColumnExpr<C>>,
     pub(super) sum_expr: Vec<AliasedDynProofExpr<C>>,
+    pub(super) max_expr: Vec<AliasedDynProofExpr<C>>,
+    pub(super) min_expr: Vec<AliasedDynProofExpr<C>>,
     pub(super) count_alias: Identifier,
     pub(super) table: TableExpr,
     pub(super) where_clause: DynProofExpr<C>,
@@ -54,6 +67,8 @@ impl<C: Commitment> GroupByExec<C> {
     pub fn new(
         group_by_exprs: Vec<ColumnExpr<C>>,
         sum_expr: Vec<AliasedDynProofExpr<C>>,
+        max_expr: Vec<AliasedDynProofExpr<C>>,
+        min_expr: Vec<AliasedDynProofExpr<C>>,
         count_alias: Identifier,
         table: TableExpr,
         where_clause: DynProofExpr<C>,
@@ -61,11 +76,98 @@ impl<C: Commitment> GroupByExec<C> {
         Self {
             group_by_exprs,
             sum_expr,
+            max_expr,
+            min_expr,
             count_alias,
             table,
             where_clause,
         }
     }
+
+    /// The `MAX` and then the `MIN` aggregates, each paired with whether it is a `MAX`
+    fn extremum_exprs(&self) -> impl Iterator<Item = (&AliasedDynProofExpr<C>, bool)> {
+        self.max_expr
+            .iter()
+            .map(|expr| (expr, true))
+            .chain(self.min_expr.iter().map(|expr| (expr, false)))
+    }
+
+    /// Aggregate the selected rows, giving an aggregate without `GROUP BY` its single row even
+    /// when no rows are selected
+    ///
+    /// # Panics
+    /// Panics if the columns cannot be aggregated, which the planner never allows.
+    fn aggregate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        group_by_columns: &[Column<'a, S>],
+        sum_columns: &[Column<'a, S>],
+        selection: &[bool],
+    ) -> AggregatedColumns<'a, S> {
+        let mut aggregated =
+            aggregate_columns(alloc, group_by_columns, sum_columns, &[], &[], selection)
+                .expect("columns should be aggregatable");
+        if self.group_by_exprs.is_empty() && aggregated.count_column.is_empty() {
+            aggregated.count_column = alloc.alloc_slice_fill_default(1);
+            aggregated.sum_columns =
+                vec![alloc.alloc_slice_fill_default(1) as &[_]; sum_columns.len()];
+        }
+        aggregated
+    }
+
+    /// Check the parts of the result that the proof does not cover, returning whether it is the
+    /// single row of an aggregate without `GROUP BY` over no selected rows
+    fn verify_result(&self, table: &OwnedTable<C::Scalar>) -> Result<bool, ProofError> {
+        let cols = self
+            .group_by_exprs
+            .iter()
+            .map(|col| table.inner_table().get(&col.column_id()))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(ProofError::VerificationError {
+                error: "Result does not all correct group by columns.",
+            })?;
+        if (1..table.num_rows()).any(|i| compare_indexes_by_owned_columns(&cols, i - 1, i).is_ge())
+        {
+            Err(ProofError::VerificationError {
+                error: "Result of group by not ordered as expected.",
+            })?;
+        }
+        let Some(OwnedColumn::BigInt(counts)) = table.inner_table().get(&self.count_alias) else {
+            return Err(ProofError::VerificationError {
+                error: "Result does not have the count column.",
+            });
+        };
+        if !self.group_by_exprs.is_empty() {
+            // A group without selected rows would not change the group by argument
+            if counts.iter().any(|&count| count <= 0) {
+                Err(ProofError::VerificationError {
+                    error: "Result of group by has a group without rows.",
+                })?;
+            }
+            return Ok(false);
+        }
+        if counts.len() != 1 {
+            Err(ProofError::VerificationError {
+                error: "Result of an aggregate without group by does not have exactly one row.",
+            })?;
+        }
+        if counts[0] != 0 {
+            return Ok(false);
+        }
+        // With no selected rows the proof does not constrain the extrema
+        for (aliased_expr, _) in self.extremum_exprs() {
+            let extremum = table
+                .inner_table()
+                .get(&aliased_expr.alias)
+                .and_then(|column| column.literal_at(0));
+            if extremum != Some(empty_extremum(aliased_expr.expr.data_type())) {
+                Err(ProofError::VerificationError {
+                    error: "Extremum over no rows is not zero.",
+                })?;
+            }
+        }
+        Ok(true)
+    }
 }
 
 impl<C: Commitment> ProofPlan<C> for GroupByExec<C> {
@@ -83,6 +185,16 @@ impl<C: Commitment> ProofPlan<C> for GroupByExec<C> {
             aliased_expr.expr.count(builder)?;
             builder.count_intermediate_mles(1);
         }
+        // For each extremum: the result column, the broadcast extremum, the indicator and the gap
+        for (aliased_expr, _) in self.extremum_exprs() {
+            aliased_expr.expr.count(builder)?;
+            builder.count_intermediate_mles(4);
+            builder.count_subpolynomials(2);
+        }
+        // The gaps are only sign checked after every input has been evaluated
+        for _ in self.extremum_exprs() {
+            count_sign(builder)?;
+        }
         // For the count col
         builder.count_intermediate_mles(1);
         builder.count_intermediate_mles(2);
@@ -100,6 +212,16 @@ impl<C: Commitment> ProofPlan<C> for GroupByExec<C> {
         accessor.get_offset(self.table.table_ref)
     }
 
+    /// Every group contains at least one input row, except the single row of an aggregate without
+    /// `GROUP BY`
+    fn max_output_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        if self.group_by_exprs.is_empty() {
+            1
+        } else {
+            self.get_length(accessor)
+        }
+    }
+
     #[allow(unused_variables)]
     fn verifier_evaluate(
         &self,
@@ -120,6 +242,15 @@ impl<C: Commitment> ProofPlan<C> for GroupByExec<C> {
             .iter()
             .map(|aliased_expr| aliased_expr.expr.verifier_evaluate(builder, accessor))
             .collect::<Result<Vec<_>, _>>()?;
+        let extremum_evals = self
+            .extremum_exprs()
+            .map(|(aliased_expr, _)| aliased_expr.expr.verifier_evaluate(builder, accessor))
+            .collect::<Result<Vec<_>, _>>()?;
+        let Some(table) = result else {
+            todo!("GroupByExec currently only supported at top level of query plan.")
+        };
+        let is_empty_aggregate = self.verify_result(table)?;
+
         // 3. filtered_columns
 
         let group_by_result_columns_evals: Vec<_> =
@@ -129,46 +260,60 @@ impl<C: Commitment> ProofPlan<C> for GroupByExec<C> {
         let sum_result_columns_evals: Vec<_> = repeat_with(|| builder.consume_intermediate_mle())
             .take(self.sum_expr.len())
             .collect();
+        let extremum_result_columns_evals: Vec<_> =
+            repeat_with(|| builder.consume_intermediate_mle())
+                .take(extremum_evals.len())
+                .collect();
         let count_column_eval = builder.consume_intermediate_mle();
 
         let alpha = builder.consume_post_result_challenge();
         let beta = builder.consume_post_result_challenge();
 
+        // 4. extrema
+        let (broadcast_evals, indicator_evals): (Vec<_>, Vec<_>) = self
+            .extremum_exprs()
+            .zip(&extremum_evals)
+            .map(|((_, is_max), &eval)| verify_extremum(builder, where_eval, eval, is_max))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        // Every selected row is grouped by its extrema as well, and each group has exactly one
+        // indicated row, so both are checked by the same argument as the group by itself.
+        // The exception is an aggregate without `GROUP BY` over no selected rows, which has none.
+        let indicator_sum_eval = if is_empty_aggregate {
+            C::Scalar::zero()
+        } else {
+            builder.mle_evaluations.output_one_evaluation
+        };
         verify_group_by(
             builder,
             alpha,
             beta,
-            (group_by_evals, aggregate_evals, where_eval),
             (
-                group_by_result_columns_evals.clone(),
-                sum_result_columns_evals.clone(),
+                group_by_evals.into_iter().chain(broadcast_evals).collect(),
+                aggregate_evals.into_iter().chain(indicator_evals).collect(),
+                where_eval,
+            ),
+            (
+                group_by_result_columns_evals
+                    .iter()
+                    .chain(&extremum_result_columns_evals)
+                    .copied()
+                    .collect(),
+                sum_result_columns_evals
+                    .iter()
+                    .copied()
+                    .chain(iter::repeat(indicator_sum_eval).take(extremum_evals.len()))
+                    .collect(),
                 count_column_eval,
             ),
         )?;
-        match result {
-            Some(table) => {
-                let cols = self
-                    .group_by_exprs
-                    .iter()
-                    .map(|col| table.inner_table().get(&col.column_id()))
-                    .collect::<Option<Vec<_>>>()
-                    .ok_or(ProofError::VerificationError {
-                        error: "Result does not all correct group by columns.",
-                    })?;
-                if (0..table.num_rows() - 1)
-                    .any(|i| compare_indexes_by_owned_columns(&cols, i, i + 1).is_ge())
-                {
-                    Err(ProofError::VerificationError {
-                        error: "Result of group by not ordered as expected.",
-                    })?;
-                }
-            }
-            None => todo!("GroupByExec currently only supported at top level of query plan."),
-        }
 
         Ok(group_by_result_columns_evals
             .into_iter()
             .chain(sum_result_columns_evals)
+            .chain(extremum_result_columns_evals)
             .chain(iter::once(count_column_eval))
             .collect::<Vec<_>>())
     }
@@ -178,9 +323,15 @@ impl<C: Commitment> ProofPlan<C> for GroupByExec<C> {
         self.group_by_exprs
             .iter()
             .map(|col| col.get_column_field())
-            .chain(self.sum_expr.iter().map(|aliased_expr| {
-                ColumnField::new(aliased_expr.alias, aliased_expr.expr.data_type())
-            }))
+            .chain(
+                self.sum_expr
+                    .iter()
+                    .chain(&self.max_expr)
+                    .chain(&self.min_expr)
+                    .map(|aliased_expr| {
+                        ColumnField::new(aliased_expr.alias, aliased_expr.expr.data_type())
+                    }),
+            )
             .chain(iter::once(ColumnField::new(
                 self.count_alias,
                 ColumnType::BigInt,
@@ -194,7 +345,12 @@ impl<C: Commitment> ProofPlan<C> for GroupByExec<C> {
         for col in &self.group_by_exprs {
             columns.insert(col.get_column_reference());
         }
-        for aliased_expr in &self.sum_expr {
+        for aliased_expr in self
+            .sum_expr
+            .iter()
+            .chain(&self.max_expr)
+            .chain(&self.min_expr)
+        {
             aliased_expr.expr.get_column_references(&mut columns);
         }
 
@@ -240,18 +396,33 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for GroupByExec<C> {
                     .result_evaluate(input_length, alloc, accessor)
             })
             .collect::<Vec<_>>();
+        let extremum_columns = self
+            .extremum_exprs()
+            .map(|(aliased_expr, _)| {
+                aliased_expr
+                    .expr
+                    .result_evaluate(input_length, alloc, accessor)
+            })
+            .collect::<Vec<_>>();
         // Compute filtered_columns
         let AggregatedColumns {
             group_by_columns: group_by_result_columns,
             sum_columns: sum_result_columns,
             count_column,
             ..
-        } = aggregate_columns(alloc, &group_by_columns, &sum_columns, &[], &[], selection)
-            .expect("columns should be aggregatable");
+        } = self.aggregate(alloc, &group_by_columns, &sum_columns, selection);
+        let groups = selected_groups(&group_by_columns, selection);
+        let extremum_result_columns =
+            self.extremum_exprs()
+                .zip(extremum_columns)
+                .map(|((_, is_max), column)| {
+                    ExtremumWitness::new(alloc, column, &groups, is_max).result
+                });
         let sum_result_columns_iter = sum_result_columns.iter().map(|col| Column::Scalar(col));
         group_by_result_columns
             .into_iter()
             .chain(sum_result_columns_iter)
+            .chain(extremum_result_columns)
             .chain(iter::once(Column::BigInt(count_column)))
             .collect::<Vec<_>>()
     }
@@ -286,14 +457,23 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for GroupByExec<C> {
             .iter()
             .map(|aliased_expr| aliased_expr.expr.prover_evaluate(builder, alloc, accessor))
             .collect::<Vec<_>>();
+        let extremum_columns = self
+            .extremum_exprs()
+            .map(|(aliased_expr, _)| aliased_expr.expr.prover_evaluate(builder, alloc, accessor))
+            .collect::<Vec<_>>();
         // 3. Compute filtered_columns
         let AggregatedColumns {
             group_by_columns: group_by_result_columns,
             sum_columns: sum_result_columns,
             count_column,
             ..
-        } = aggregate_columns(alloc, &group_by_columns, &sum_columns, &[], &[], selection)
-            .expect("columns should be aggregatable");
+        } = self.aggregate(alloc, &group_by_columns, &sum_columns, selection);
+        let groups = selected_groups(&group_by_columns, selection);
+        let extremum_witnesses = self
+            .extremum_exprs()
+            .zip(&extremum_columns)
+            .map(|((_, is_max), &column)| ExtremumWitness::new(alloc, column, &groups, is_max))
+            .collect::<Vec<_>>();
 
         let alpha = builder.consume_post_result_challenge();
         let beta = builder.consume_post_result_challenge();
@@ -304,20 +484,64 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for GroupByExec<C> {
             .clone()
             .into_iter()
             .chain(sum_result_columns_iter)
+            .chain(extremum_witnesses.iter().map(|witness| witness.result))
             .chain(core::iter::once(Column::BigInt(count_column)))
             .collect::<Vec<_>>();
         // 5. Produce MLEs
         res.iter().copied().for_each(|column| {
             builder.produce_intermediate_mle(column);
         });
-        // 6. Prove group by
+        // 6. Prove extrema
+        for (((_, is_max), &column), witness) in self
+            .extremum_exprs()
+            .zip(&extremum_columns)
+            .zip(&extremum_witnesses)
+        {
+            prove_extremum(builder, alloc, selection, column, witness, is_max);
+        }
+        // 7. Prove group by, with the extrema as extra group by columns and the indicators as extra sums
+        let indicator_sums = alloc.alloc_slice_fill_with(count_column.len(), |i| {
+            if count_column[i] == 0 {
+                C::Scalar::zero()
+            } else {
+                C::Scalar::one()
+            }
+        });
         prove_group_by(
             builder,
             alloc,
             alpha,
             beta,
-            (&group_by_columns, &sum_columns, selection),
-            (&group_by_result_columns, &sum_result_columns, count_column),
+            (
+                &group_by_columns
+                    .into_iter()
+                    .chain(
+                        extremum_witnesses
+                            .iter()
+                            .map(|witness| Column::Scalar(witness.broadcast)),
+                    )
+                    .collect::<Vec<_>>(),
+                &sum_columns
+                    .into_iter()
+                    .chain(
+                        extremum_witnesses
+                            .iter()
+                            .map(|witness| Column::Scalar(witness.indicator)),
+                    )
+                    .collect::<Vec<_>>(),
+                selection,
+            ),
+            (
+                &group_by_result_columns
+                    .into_iter()
+                    .chain(extremum_witnesses.iter().map(|witness| witness.result))
+                    .collect::<Vec<_>>(),
+                &sum_result_columns
+                    .into_iter()
+                    .chain(iter::repeat(indicator_sums as &[_]).take(extremum_witnesses.len()))
+                    .collect::<Vec<_>>(),
+                count_column,
+            ),
         );
         res
     }
@@ -332,11 +556,12 @@ fn verify_group_by<C: Commitment>(
     (g_out_evals, sum_out_evals, count_out_eval): (Vec<C::Scalar>, Vec<C::Scalar>, C::Scalar),
 ) -> Result<(), ProofError> {
     let one_eval = builder.mle_evaluations.input_one_evaluation;
+    let output_one_eval = builder.mle_evaluations.output_one_evaluation;
 
     // g_in_fold = alpha + sum beta^j * g_in[j]
     let g_in_fold_eval = alpha * one_eval + fold_vals(beta, &g_in_evals);
     // g_out_bar_fold = alpha + sum beta^j * g_out_bar[j]
-    let g_out_bar_fold_eval = alpha * one_eval + fold_vals(beta, &g_out_evals);
+    let g_out_bar_fold_eval = alpha * output_one_eval + fold_vals(beta, &g_out_evals);
     // sum_in_fold = 1 + sum beta^(j+1) * sum_in[j]
     let sum_in_fold_eval = one_eval + beta * fold_vals(beta, &sum_in_evals);
     // sum_out_bar_fold = count_out_bar + sum beta^(j+1) * sum_out_bar[j]
@@ -360,16 +585,12 @@ fn verify_group_by<C: Commitment>(
     // g_out_star * g_out_bar_fold - 1 = 0
     builder.produce_sumcheck_subpolynomial_evaluation(
         &SumcheckSubpolynomialType::Identity,
-        g_out_star_eval * g_out_bar_fold_eval - one_eval,
+        g_out_star_eval * g_out_bar_fold_eval - output_one_eval,
     );
 
     Ok(())
 }
 
-#[allow(
-    clippy::missing_panics_doc,
-    reason = "alpha is guaranteed to not be zero in this context"
-)]
 pub fn prove_group_by<'a, S: Scalar>(
     builder: &mut FinalRoundBuilder<'a, S>,
     alloc: &'a Bump,
@@ -386,7 +607,7 @@ pub fn prove_group_by<'a, S: Scalar>(
     fold_columns(g_in_fold, One::one(), beta, g_in);
 
     // g_out_bar_fold = alpha + sum beta^j * g_out_bar[j]
-    let g_out_bar_fold = alloc.alloc_slice_fill_copy(n, alpha);
+    let g_out_bar_fold = alloc.alloc_slice_fill_copy(m_out, alpha);
     fold_columns(g_out_bar_fold, One::one(), beta, g_out);
 
     // sum_in_fold = 1 + sum beta^(j+1) * sum_in[j]
@@ -394,7 +615,7 @@ pub fn prove_group_by<'a, S: Scalar>(
     fold_columns(sum_in_fold, beta, beta, sum_in);
 
     // sum_out_bar_fold = count_out_bar + sum beta^(j+1) * sum_out_bar[j]
-    let sum_out_bar_fold = alloc.alloc_slice_fill_default(n);
+    let sum_out_bar_fold = alloc.alloc_slice_fill_default(m_out);
     slice_ops::slice_cast_mut(count_out, sum_out_bar_fold);
     fold_columns(sum_out_bar_fold, beta, beta, sum_out);
 
@@ -402,10 +623,14 @@ pub fn prove_group_by<'a, S: Scalar>(
     let g_in_star = alloc.alloc_slice_copy(g_in_fold);
     slice_ops::batch_inversion(g_in_star);
 
-    // g_out_star = g_out_bar_fold^(-1), which is simply alpha^(-1) when beyond the output length
+    // g_out_star = g_out_bar_fold^(-1)
     let g_out_star = alloc.alloc_slice_copy(g_out_bar_fold);
-    g_out_star[m_out..].fill(alpha.inv().expect("alpha should never be 0"));
-    slice_ops::batch_inversion(&mut g_out_star[..m_out]);
+    slice_ops::batch_inversion(g_out_star);
+
+    // The output may be longer than the input, so the constants are explicit rather than
+    // ranging over every row
+    let ones_in = alloc.alloc_slice_fill_copy(n, S::one());
+    let ones_out = alloc.alloc_slice_fill_copy(m_out, S::one());
 
     builder.produce_intermediate_mle(g_in_star as &[_]);
     builder.produce_intermediate_mle(g_out_star as &[_]);
@@ -440,7 +665,7 @@ pub fn prove_group_by<'a, S: Scalar>(
                 S::one(),
                 vec![Box::new(g_in_star as &[_]), Box::new(g_in_fold as &[_])],
             ),
-            (-S::one(), vec![]),
+            (-S::one(), vec![Box::new(ones_in as &[_])]),
         ],
     );
 
@@ -455,7 +680,211 @@ pub fn prove_group_by<'a, S: Scalar>(
                     Box::new(g_out_bar_fold as &[_]),
                 ],
             ),
-            (-S::one(), vec![]),
+            (-S::one(), vec![Box::new(ones_out as &[_])]),
         ],
     );
 }
+
+/// The indexes of the selected rows of each group, with the groups in the same order as
+/// the output of [`aggregate_columns`]. Without group by columns there is exactly one group,
+/// which is empty when no rows are selected.
+fn selected_groups<S: Scalar>(
+    group_by_columns: &[Column<S>],
+    selection: &[bool],
+) -> Vec<Vec<usize>> {
+    let mut filtered_indexes: Vec<_> = selection
+        .iter()
+        .enumerate()
+        .filter(|&(_, &b)| b)
+        .map(|(i, _)| i)
+        .collect();
+    if group_by_columns.is_empty() {
+        return vec![filtered_indexes];
+    }
+    filtered_indexes.sort_unstable_by(|&a, &b| compare_indexes_by_columns(group_by_columns, a, b));
+    filtered_indexes
+        .chunk_by(|&a, &b| compare_indexes_by_columns(group_by_columns, a, b).is_eq())
+        .map(<[usize]>::to_vec)
+        .collect()
+}
+
+/// The `MAX` or `MIN` of no rows, which is zero as `NULL` is not supported
+fn empty_extremum<S: Scalar>(column_type: ColumnType) -> LiteralValue<S> {
+    match column_type {
+        ColumnType::Boolean => LiteralValue::Boolean(false),
+        ColumnType::TinyInt => LiteralValue::TinyInt(0),
+        ColumnType::SmallInt => LiteralValue::SmallInt(0),
+        ColumnType::Int => LiteralValue::Int(0),
+        ColumnType::BigInt => LiteralValue::BigInt(0),
+        ColumnType::Int128 => LiteralValue::Int128(0),
+        ColumnType::VarChar => LiteralValue::VarChar((String::new(), S::zero())),
+        ColumnType::Decimal75(precision, scale) => {
+            LiteralValue::Decimal75(precision, scale, S::zero())
+        }
+        ColumnType::TimestampTZ(time_unit, time_zone) => {
+            LiteralValue::TimeStampTZ(time_unit, time_zone, 0)
+        }
+        ColumnType::Scalar => LiteralValue::Scalar(S::zero()),
+    }
+}
+
+/// The columns that prove a `MAX` or `MIN` aggregate
+struct ExtremumWitness<'a, S: Scalar> {
+    /// The extremum of each group
+    result: Column<'a, S>,
+    /// The extremum of the group of each selected row, and zero for the other rows
+    broadcast: &'a [S],
+    /// One for a single row of each group whose value is the extremum, and zero elsewhere
+    indicator: &'a [S],
+}
+
+impl<'a, S: Scalar> ExtremumWitness<'a, S> {
+    /// # Panics
+    /// Panics if a group is empty, other than the single group of an aggregate without
+    /// `GROUP BY`, which [`selected_groups`] never produces.
+    fn new(alloc: &'a Bump, column: Column<'a, S>, groups: &[Vec<usize>], is_max: bool) -> Self {
+        if let [group] = groups {
+            if group.is_empty() {
+                return Self {
+                    result: Column::from_literal_with_length(
+                        &empty_extremum(column.column_type()),
+                        1,
+                        alloc,
+                    ),
+                    broadcast: alloc.alloc_slice_fill_default(column.len()),
+                    indicator: alloc.alloc_slice_fill_default(column.len()),
+                };
+            }
+        }
+        let columns = [column];
+        let extremum_indexes: Vec<_> = groups
+            .iter()
+            .map(|group| {
+                let compare =
+                    |&&a: &&usize, &&b: &&usize| compare_indexes_by_columns(&columns, a, b);
+                let extremum = if is_max {
+                    group.iter().max_by(compare)
+                } else {
+                    group.iter().min_by(compare)
+                };
+                *extremum.expect("groups are never empty")
+            })
+            .collect();
+        let broadcast = alloc.alloc_slice_fill_default(column.len());
+        let indicator = alloc.alloc_slice_fill_default(column.len());
+        for (group, &extremum_index) in groups.iter().zip(&extremum_indexes) {
+            let extremum = column
+                .scalar_at(extremum_index)
+                .expect("extremum index is within the column");
+            for &i in group {
+                broadcast[i] = extremum;
+            }
+            indicator[extremum_index] = S::one();
+        }
+        Self {
+            result: filter_column_by_index(alloc, &column, &extremum_indexes),
+            broadcast,
+            indicator,
+        }
+    }
+}
+
+/// Prove that the broadcast extremum bounds every selected row of its group and that the
+/// indicated rows attain it
+fn prove_extremum<'a, S: Scalar>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    alloc: &'a Bump,
+    selection: &'a [bool],
+    column: Column<'a, S>,
+    witness: &ExtremumWitness<'a, S>,
+    is_max: bool,
+) {
+    let values = column.as_scalar(alloc);
+    builder.produce_intermediate_mle(witness.broadcast);
+    builder.produce_intermediate_mle(witness.indicator);
+
+    // indicator * (broadcast - column) = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (
+                S::one(),
+                vec![Box::new(witness.indicator), Box::new(witness.broadcast)],
+            ),
+            (
+                -S::one(),
+                vec![Box::new(witness.indicator), Box::new(column)],
+            ),
+        ],
+    );
+
+    // gap = selection * (broadcast - column) for MAX and selection * (column - broadcast) for MIN
+    let sign = if is_max { S::one() } else { -S::one() };
+    let gap = alloc.alloc_slice_fill_with(values.len(), |i| {
+        if selection[i] {
+            sign * (witness.broadcast[i] - values[i])
+        } else {
+            S::zero()
+        }
+    });
+    builder.produce_intermediate_mle(gap as &[_]);
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(gap as &[_])]),
+            (
+                -sign,
+                vec![Box::new(selection), Box::new(witness.broadcast)],
+            ),
+            (sign, vec![Box::new(selection), Box::new(column)]),
+        ],
+    );
+
+    // 0 <= gap
+    prover_evaluate_sign(
+        builder,
+        alloc,
+        gap,
+        #[cfg(test)]
+        false,
+    );
+}
+
+/// Verify the constraints of [`prove_extremum`], returning the evaluations of the broadcast
+/// extremum and of the indicator
+fn verify_extremum<C: Commitment>(
+    builder: &mut VerificationBuilder<C>,
+    selection_eval: C::Scalar,
+    column_eval: C::Scalar,
+    is_max: bool,
+) -> Result<(C::Scalar, C::Scalar), ProofError> {
+    let one_eval = builder.mle_evaluations.input_one_evaluation;
+    let broadcast_eval = builder.consume_intermediate_mle();
+    let indicator_eval = builder.consume_intermediate_mle();
+
+    // indicator * (broadcast - column) = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &SumcheckSubpolynomialType::Identity,
+        indicator_eval * (broadcast_eval - column_eval),
+    );
+
+    // gap = selection * (broadcast - column) for MAX and selection * (column - broadcast) for MIN
+    let sign = if is_max {
+        C::Scalar::one()
+    } else {
+        -C::Scalar::one()
+    };
+    let gap_eval = builder.consume_intermediate_mle();
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &SumcheckSubpolynomialType::Identity,
+        gap_eval - sign * selection_eval * (broadcast_eval - column_eval),
+    );
+
+    // 0 <= gap
+    if !verifier_evaluate_sign(builder, gap_eval, one_eval)?.is_zero() {
+        return Err(ProofError::VerificationError {
+            error: "extremum does not bound every row of its group",
+        });
+    }
+    Ok((broadcast_eval, indicator_eval))
+}
@@ -6,7 +6,7 @@ use crate::{
         scalar::Curve25519Scalar,
     },
     sql::{
-        proof::{exercise_verification, VerifiableQueryResult},
+        proof::{exercise_verification, QueryProof, VerifiableQueryResult},
         proof_exprs::test_utility::*,
     },
 };
@@ -40,6 +40,75 @@ fn we_can_prove_a_simple_group_by_with_bigint_columns() {
     assert_eq!(res, expected);
 }
 
+/// `select count(*) as __count__ from sxt.t where b = 98`
+#[test]
+fn we_can_prove_a_count_without_group_by_columns_that_matches_no_rows() {
+    let data = owned_table([bigint("a", [1, 2, 2]), bigint("b", [99, 99, 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by(
+        vec![],
+        vec![],
+        "__count__",
+        tab(t),
+        equal(column(t, "b", &accessor), const_int128(98)),
+    );
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([bigint("__count__", [0])]);
+    assert_eq!(res, expected);
+}
+
+/// `select a, sum(c) as sum_c, count(*) as __count__ from sxt.t where b = 98 group by a`
+#[test]
+fn we_can_prove_a_group_by_that_matches_no_rows() {
+    let data = owned_table([
+        bigint("a", [1, 2, 2]),
+        bigint("b", [99, 99, 0]),
+        bigint("c", [101, 102, 103]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by(
+        cols_expr(t, &["a"], &accessor),
+        vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+        "__count__",
+        tab(t),
+        equal(column(t, "b", &accessor), const_int128(98)),
+    );
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [0; 0]),
+        bigint("sum_c", [0; 0]),
+        bigint("__count__", [0; 0]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+/// `select count(*) as __count__ from sxt.t where b = 98` on an empty table
+#[test]
+fn we_can_prove_a_count_on_an_empty_table_with_a_query_proof() {
+    let data = owned_table([bigint("b", [0; 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by(
+        vec![],
+        vec![],
+        "__count__",
+        tab(t),
+        equal(column(t, "b", &accessor), const_int128(98)),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let res = proof.verify(&expr, &accessor, &result, &()).unwrap().table;
+    let expected = owned_table([bigint("__count__", [0])]);
+    assert_eq!(res, expected);
+}
+
 /// `select a, sum(c * 2 + 1) as sum_c, count(*) as __count__ from sxt.t where b = 99 group by a`
 #[test]
 fn we_can_prove_a_group_by_with_bigint_columns() {
@@ -230,3 +299,67 @@ fn we_can_prove_a_complex_group_by_query_with_many_columns() {
     ]);
     assert_eq!(res, expected);
 }
+
+/// `select a, max(c) as max_c, min(c) as min_c, min(d) as min_d, count(*) as __count__ from sxt.t where b = 99 group by a`
+#[test]
+fn we_can_prove_a_group_by_with_max_and_min() {
+    let data = owned_table([
+        bigint("a", [1, 2, 2, 1, 2, 3]),
+        bigint("b", [99, 99, 99, 99, 0, 99]),
+        bigint("c", [101, -102, 103, 104, 105, 7]),
+        int128("d", [-5, 6, 6, 8, -100, 0]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by_with_extrema(
+        cols_expr(t, &["a"], &accessor),
+        vec![],
+        vec![max_expr(column(t, "c", &accessor), "max_c")],
+        vec![
+            min_expr(column(t, "c", &accessor), "min_c"),
+            min_expr(column(t, "d", &accessor), "min_d"),
+        ],
+        "__count__",
+        tab(t),
+        equal(column(t, "b", &accessor), const_int128(99)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [1, 2, 3]),
+        bigint("max_c", [104, 103, 7]),
+        bigint("min_c", [101, -102, 7]),
+        int128("min_d", [-5, 6, 0]),
+        bigint("__count__", [2, 2, 1]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+/// `select sum(c) as sum_c, max(c) as max_c, count(*) as __count__ from sxt.t where b = 98`
+#[test]
+fn we_can_prove_a_max_without_group_by_columns_that_matches_no_rows() {
+    let data = owned_table([bigint("b", [99, 99, 0]), bigint("c", [1, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by_with_extrema(
+        vec![],
+        vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+        vec![max_expr(column(t, "c", &accessor), "max_c")],
+        vec![],
+        "__count__",
+        tab(t),
+        equal(column(t, "b", &accessor), const_int128(98)),
+    );
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("sum_c", [0]),
+        bigint("max_c", [0]),
+        bigint("__count__", [0]),
+    ]);
+    assert_eq!(res, expected);
+}
@@ -27,5 +27,10 @@ pub(crate) use group_by_exec::GroupByExec;
 #[cfg(all(test, feature = "blitzar"))]
 mod group_by_exec_test;
 
+mod alias_exec;
+pub(crate) use alias_exec::AliasExec;
+#[cfg(all(test, feature = "blitzar"))]
+mod alias_exec_test;
+
 mod dyn_proof_plan;
 pub use dyn_proof_plan::DynProofPlan;
@@ -18,13 +18,15 @@ use crate::{
 };
 use alloc::vec::Vec;
 use bumpalo::Bump;
-use core::iter::repeat_with;
 use serde::{Deserialize, Serialize};
 
 /// Provable expressions for queries of the form
 /// ```ignore
 ///     SELECT <result_expr1>, ..., <result_exprN> FROM <table>
 /// ```
+///
+/// The result columns are not committed to. The verifier evaluates the result expressions from
+/// the input columns and checks them against the evaluations of the result itself.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProjectionExec<C: Commitment> {
     pub(super) aliased_results: Vec<AliasedDynProofExpr<C>>,
@@ -49,7 +51,6 @@ impl<C: Commitment> ProofPlan<C> for ProjectionExec<C> {
     ) -> Result<(), ProofError> {
         for aliased_expr in &self.aliased_results {
             aliased_expr.expr.count(builder)?;
-            builder.count_intermediate_mles(1);
         }
         Ok(())
     }
@@ -62,6 +63,11 @@ impl<C: Commitment> ProofPlan<C> for ProjectionExec<C> {
         accessor.get_offset(self.table.table_ref)
     }
 
+    /// Every input row produces exactly one output row
+    fn max_output_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        self.get_length(accessor)
+    }
+
     #[allow(unused_variables)]
     fn verifier_evaluate(
         &self,
@@ -72,10 +78,7 @@ impl<C: Commitment> ProofPlan<C> for ProjectionExec<C> {
         self.aliased_results
             .iter()
             .map(|aliased_expr| aliased_expr.expr.verifier_evaluate(builder, accessor))
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(repeat_with(|| builder.consume_intermediate_mle())
-            .take(self.aliased_results.len())
-            .collect::<Vec<_>>())
+            .collect()
     }
 
     fn get_column_result_fields(&self) -> Vec<ColumnField> {
@@ -132,16 +135,10 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for ProjectionExec<C> {
         alloc: &'a Bump,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Vec<Column<'a, C::Scalar>> {
-        // 1. Evaluate result expressions
-        let res: Vec<_> = self
-            .aliased_results
+        // Evaluate result expressions
+        self.aliased_results
             .iter()
             .map(|aliased_expr| aliased_expr.expr.prover_evaluate(builder, alloc, accessor))
-            .collect();
-        // 2. Produce MLEs
-        res.clone().into_iter().for_each(|column| {
-            builder.produce_intermediate_mle(column.as_scalar(alloc));
-        });
-        res
+            .collect()
     }
 }
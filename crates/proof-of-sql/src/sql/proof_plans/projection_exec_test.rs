@@ -12,7 +12,7 @@ use crate::{
     sql::{
         proof::{
             exercise_verification, FirstRoundBuilder, ProofPlan, ProvableQueryResult,
-            ProverEvaluate, VerifiableQueryResult,
+            ProverEvaluate, QueryProof, VerifiableQueryResult,
         },
         proof_exprs::{test_utility::*, ColumnExpr, DynProofExpr, TableExpr},
     },
@@ -349,3 +349,145 @@ fn we_can_prove_a_projection() {
     ]);
     assert_eq!(res, expected);
 }
+
+// With one or two rows the proof has a single sumcheck variable, the smallest size it supports.
+fn prove_a_projection_on_a_table_with_a_single_sumcheck_variable(
+    a: &[i64],
+    d: &[&str],
+    offset: usize,
+) {
+    let data = owned_table([bigint("a", a.to_vec()), varchar("d", d.to_vec())]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, offset);
+    let expr = projection(
+        vec![
+            col_expr_plan(t, "d", &accessor),
+            aliased_plan(
+                add(column(t, "a", &accessor), const_bigint(1)),
+                "a_plus_one",
+            ),
+            aliased_plan(gte(column(t, "a", &accessor), const_bigint(102)), "big"),
+        ],
+        tab(t),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        varchar("d", d.to_vec()),
+        bigint("a_plus_one", a.iter().map(|a| a + 1)),
+        boolean("big", a.iter().map(|&a| a >= 102)),
+    ]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn we_can_prove_a_projection_on_a_two_row_table() {
+    prove_a_projection_on_a_table_with_a_single_sumcheck_variable(&[105, 101], &["x", "y"], 0);
+    prove_a_projection_on_a_table_with_a_single_sumcheck_variable(&[105, 101], &["x", "y"], 3);
+}
+
+#[test]
+fn we_can_prove_a_projection_on_a_one_row_table() {
+    prove_a_projection_on_a_table_with_a_single_sumcheck_variable(&[105], &["x"], 0);
+    prove_a_projection_on_a_table_with_a_single_sumcheck_variable(&[101], &["x"], 3);
+}
+
+#[test]
+fn we_can_prove_a_projection_of_literals_without_committing_to_them() {
+    let data = owned_table([bigint("a", [1, 2, 3]), varchar("d", ["x", "y", "z"])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = projection(
+        vec![
+            aliased_plan(const_bigint(1), "flag"),
+            col_expr_plan(t, "a", &accessor),
+            aliased_plan(const_varchar("tag"), "tag"),
+        ],
+        tab(t),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("flag", [1; 3]),
+        bigint("a", [1, 2, 3]),
+        varchar("tag", ["tag"; 3]),
+    ]);
+    assert_eq!(res, expected);
+
+    let (proof, _) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let (column_only_proof, _) = QueryProof::<InnerProductProof>::new(
+        &projection(cols_expr_plan(t, &["a"], &accessor), tab(t)),
+        &accessor,
+        &(),
+    );
+    assert_eq!(proof.summary(), column_only_proof.summary());
+}
+
+#[test]
+fn we_can_prove_a_projection_of_only_literals() {
+    let data = owned_table([bigint("a", [1, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = projection(
+        vec![
+            aliased_plan(const_bool(true), "flag"),
+            aliased_plan(const_int128(-7), "seven"),
+        ],
+        tab(t),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([boolean("flag", [true; 3]), int128("seven", [-7; 3])]);
+    assert_eq!(res, expected);
+
+    let (proof, _) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert_eq!(proof.summary().commitments, 0);
+}
+
+#[test]
+fn we_can_prove_a_projection_without_committing_to_the_result_columns() {
+    let data = owned_table([bigint("a", [1, 2, 3]), bigint("b", [4, 5, 6])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = projection(
+        vec![
+            col_expr_plan(t, "a", &accessor),
+            aliased_plan(
+                add(column(t, "a", &accessor), column(t, "b", &accessor)),
+                "sum",
+            ),
+            col_expr_plan(t, "b", &accessor),
+        ],
+        tab(t),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [1, 2, 3]),
+        bigint("sum", [5, 7, 9]),
+        bigint("b", [4, 5, 6]),
+    ]);
+    assert_eq!(res, expected);
+
+    // The result is checked against the input columns, which are each opened once
+    let (proof, _) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert_eq!(proof.summary().commitments, 0);
+    assert_eq!(proof.summary().pcs_proof_evaluations, 2);
+    let forged_result = ProvableQueryResult::new(
+        3,
+        &[
+            Column::<Curve25519Scalar>::BigInt(&[1, 2, 4]),
+            Column::BigInt(&[5, 7, 10]),
+            Column::BigInt(&[4, 5, 6]),
+        ],
+    );
+    assert!(proof.verify(&expr, &accessor, &forged_result, &()).is_err());
+}
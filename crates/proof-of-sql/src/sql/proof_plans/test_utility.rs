@@ -1,4 +1,4 @@
-use super::{DynProofPlan, FilterExec, GroupByExec, ProjectionExec};
+use super::{AliasExec, DynProofPlan, FilterExec, GroupByExec, ProjectionExec};
 use crate::{
     base::commitment::Commitment,
     sql::proof_exprs::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, TableExpr},
@@ -28,12 +28,47 @@ pub fn group_by<C: Commitment>(
     count_alias: &str,
     table: TableExpr,
     where_clause: DynProofExpr<C>,
+) -> DynProofPlan<C> {
+    group_by_with_extrema(
+        group_by_exprs,
+        sum_expr,
+        vec![],
+        vec![],
+        count_alias,
+        table,
+        where_clause,
+    )
+}
+
+/// # Panics
+///
+/// Will panic if `count_alias` cannot be parsed as a valid identifier.
+pub fn group_by_with_extrema<C: Commitment>(
+    group_by_exprs: Vec<ColumnExpr<C>>,
+    sum_expr: Vec<AliasedDynProofExpr<C>>,
+    max_expr: Vec<AliasedDynProofExpr<C>>,
+    min_expr: Vec<AliasedDynProofExpr<C>>,
+    count_alias: &str,
+    table: TableExpr,
+    where_clause: DynProofExpr<C>,
 ) -> DynProofPlan<C> {
     DynProofPlan::GroupBy(GroupByExec::new(
         group_by_exprs,
         sum_expr,
+        max_expr,
+        min_expr,
         count_alias.parse().unwrap(),
         table,
         where_clause,
     ))
 }
+
+/// # Panics
+///
+/// Will panic if any of the `aliases` cannot be parsed as a valid identifier.
+pub fn alias<C: Commitment>(input: DynProofPlan<C>, aliases: &[&str]) -> DynProofPlan<C> {
+    DynProofPlan::Alias(AliasExec::new(
+        input,
+        aliases.iter().map(|alias| alias.parse().unwrap()).collect(),
+    ))
+}
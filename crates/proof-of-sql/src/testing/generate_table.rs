@@ -0,0 +1,117 @@
+use crate::base::{
+    database::{ColumnType, OwnedColumn, OwnedTable},
+    scalar::Scalar,
+};
+use alloc::{string::ToString, vec::Vec};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// How the values of a generated column are distributed
+///
+/// Every row draws an integer from the distribution, which is then stored as the type of the
+/// column. See [`generate_table`] for how each type stores it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distribution {
+    /// Every integer in `min..=max` is equally likely
+    Uniform {
+        /// The smallest value
+        min: i64,
+        /// The largest value
+        max: i64,
+    },
+    /// Only the integers in `0..distinct` occur, and smaller ones are much more common
+    ///
+    /// A value is drawn as `floor(distinct * u^3)` for `u` uniform in `[0, 1)`, so `0` alone makes
+    /// up about `distinct^(-1/3)` of the rows. This gives the many duplicates of realistic
+    /// `GROUP BY` and `DISTINCT` keys.
+    Skewed {
+        /// The number of different values
+        distinct: u32,
+    },
+}
+
+impl Distribution {
+    #[allow(clippy::cast_possible_truncation)]
+    fn sample(self, rng: &mut impl Rng) -> i64 {
+        match self {
+            Distribution::Uniform { min, max } => rng.gen_range(min..=max),
+            Distribution::Skewed { distinct } => {
+                let u: f64 = rng.gen();
+                (f64::from(distinct) * u.powi(3)) as i64
+            }
+        }
+    }
+}
+
+/// Generate a table of `rows` rows with the given `(name, type, distribution)` columns.
+///
+/// The same arguments always produce the same table, so benchmarks over it can be compared
+/// between runs. To prove queries over the table, add it to an
+/// [`OwnedTableTestAccessor`](crate::base::database::OwnedTableTestAccessor), which is both the
+/// data accessor and the matching commitment accessor.
+///
+/// Each drawn value is stored as follows:
+/// - Integer and timestamp columns store it as is, clamped to the range of the type.
+/// - Decimal and scalar columns store it as the unscaled value.
+/// - Boolean columns store whether it is nonzero.
+/// - Varchar columns store its decimal representation, so equal values give equal strings.
+///
+/// # Panics
+///
+/// Panics if a name is not a valid identifier, if a uniform distribution has `min > max`, or if a
+/// skewed distribution has no values.
+#[must_use]
+pub fn generate_table<S: Scalar>(
+    rows: usize,
+    columns: &[(&str, ColumnType, Distribution)],
+    seed: u64,
+) -> OwnedTable<S> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    OwnedTable::try_from_iter(columns.iter().map(|&(name, column_type, distribution)| {
+        assert!(
+            !matches!(distribution, Distribution::Skewed { distinct: 0 }),
+            "a skewed distribution needs at least one value"
+        );
+        let values: Vec<i64> = (0..rows).map(|_| distribution.sample(&mut rng)).collect();
+        (
+            name.parse().expect("column names are valid identifiers"),
+            to_column(column_type, values),
+        )
+    }))
+    .expect("generated columns all have the same length")
+}
+
+/// Store drawn values as a column of `column_type`
+#[allow(clippy::cast_possible_truncation)]
+fn to_column<S: Scalar>(column_type: ColumnType, values: Vec<i64>) -> OwnedColumn<S> {
+    let clamped = |min: i64, max: i64| values.iter().map(move |x| (*x).clamp(min, max));
+    match column_type {
+        ColumnType::Boolean => OwnedColumn::Boolean(values.iter().map(|x| *x != 0).collect()),
+        ColumnType::TinyInt => OwnedColumn::TinyInt(
+            clamped(i8::MIN.into(), i8::MAX.into())
+                .map(|x| x as i8)
+                .collect(),
+        ),
+        ColumnType::SmallInt => OwnedColumn::SmallInt(
+            clamped(i16::MIN.into(), i16::MAX.into())
+                .map(|x| x as i16)
+                .collect(),
+        ),
+        ColumnType::Int => OwnedColumn::Int(
+            clamped(i32::MIN.into(), i32::MAX.into())
+                .map(|x| x as i32)
+                .collect(),
+        ),
+        ColumnType::BigInt => OwnedColumn::BigInt(values),
+        ColumnType::Int128 => OwnedColumn::Int128(values.into_iter().map(i128::from).collect()),
+        ColumnType::VarChar => {
+            OwnedColumn::VarChar(values.iter().map(ToString::to_string).collect())
+        }
+        ColumnType::Scalar => OwnedColumn::Scalar(values.into_iter().map(S::from).collect()),
+        ColumnType::Decimal75(precision, scale) => {
+            OwnedColumn::Decimal75(precision, scale, values.into_iter().map(S::from).collect())
+        }
+        ColumnType::TimestampTZ(time_unit, timezone) => {
+            OwnedColumn::TimestampTZ(time_unit, timezone, values)
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use super::{generate_table, Distribution};
+use crate::base::{
+    database::{ColumnType, OwnedColumn},
+    map::IndexMap,
+    scalar::Curve25519Scalar,
+};
+
+#[test]
+fn we_can_generate_the_same_table_from_the_same_seed() {
+    let columns = [
+        (
+            "a",
+            ColumnType::BigInt,
+            Distribution::Uniform { min: -10, max: 10 },
+        ),
+        (
+            "b",
+            ColumnType::VarChar,
+            Distribution::Skewed { distinct: 5 },
+        ),
+        (
+            "c",
+            ColumnType::Boolean,
+            Distribution::Uniform { min: 0, max: 1 },
+        ),
+    ];
+    let table = generate_table::<Curve25519Scalar>(100, &columns, 7);
+    assert_eq!(table.num_rows(), 100);
+    assert_eq!(table.num_columns(), 3);
+    assert_eq!(
+        table.column("b").unwrap().column_type(),
+        ColumnType::VarChar
+    );
+    assert_eq!(table, generate_table(100, &columns, 7));
+    assert_ne!(table, generate_table(100, &columns, 8));
+}
+
+#[test]
+fn we_can_generate_uniform_values_within_their_range() {
+    let table = generate_table::<Curve25519Scalar>(
+        1000,
+        &[
+            (
+                "a",
+                ColumnType::Int,
+                Distribution::Uniform { min: 3, max: 6 },
+            ),
+            (
+                "b",
+                ColumnType::TinyInt,
+                Distribution::Uniform {
+                    min: -1000,
+                    max: 1000,
+                },
+            ),
+        ],
+        0,
+    );
+    let OwnedColumn::Int(a) = table.column("a").unwrap() else {
+        panic!("a is an int column")
+    };
+    assert!(a.iter().all(|x| (3..=6).contains(x)));
+    for x in 3..=6 {
+        assert!(a.contains(&x));
+    }
+    let OwnedColumn::TinyInt(b) = table.column("b").unwrap() else {
+        panic!("b is a tinyint column")
+    };
+    assert!(b.contains(&i8::MIN) && b.contains(&i8::MAX));
+}
+
+#[test]
+fn we_can_generate_skewed_values_with_many_duplicates() {
+    let table = generate_table::<Curve25519Scalar>(
+        1000,
+        &[(
+            "a",
+            ColumnType::BigInt,
+            Distribution::Skewed { distinct: 100 },
+        )],
+        0,
+    );
+    let OwnedColumn::BigInt(a) = table.column("a").unwrap() else {
+        panic!("a is a bigint column")
+    };
+    assert!(a.iter().all(|x| (0..100).contains(x)));
+    let mut counts = IndexMap::<i64, usize>::default();
+    for x in a {
+        *counts.entry(*x).or_default() += 1;
+    }
+    // about a fifth of the rows are zero, rather than a hundredth
+    assert!(counts[&0] > 150);
+}
@@ -0,0 +1,5 @@
+//! Deterministic synthetic data for benchmarks and performance tests
+mod generate_table;
+pub use generate_table::{generate_table, Distribution};
+#[cfg(test)]
+mod generate_table_test;
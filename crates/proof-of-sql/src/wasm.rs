@@ -0,0 +1,36 @@
+//! Entry points for verifying proofs in the browser through `wasm-bindgen`.
+//!
+//! Every input and output crosses the boundary as bytes, so none of the proof types need bindings.
+use crate::{
+    proof_primitive::dory::{DynamicDoryEvaluationProof, VerifierSetup},
+    sql::proof,
+};
+use alloc::{format, vec::Vec};
+use ark_serialize::{CanonicalDeserialize, Compress, Validate};
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+/// Verify a Dynamic Dory query proof whose inputs are all serialized.
+///
+/// `verifier_setup_bytes` is a [`VerifierSetup`] in the format written by
+/// [`VerifierSetup::save_to_file`]. The other inputs and the output are described by
+/// [`proof::verify_serialized`].
+#[wasm_bindgen]
+pub fn verify_serialized(
+    proof_bytes: &[u8],
+    plan_bytes: &[u8],
+    commitments_bytes: &[u8],
+    result_bytes: &[u8],
+    verifier_setup_bytes: &[u8],
+) -> Result<Vec<u8>, JsError> {
+    let verifier_setup =
+        VerifierSetup::deserialize_with_mode(verifier_setup_bytes, Compress::No, Validate::No)
+            .map_err(|e| JsError::new(&format!("Failed to deserialize the verifier setup: {e}")))?;
+    proof::verify_serialized::<DynamicDoryEvaluationProof>(
+        proof_bytes,
+        plan_bytes,
+        commitments_bytes,
+        result_bytes,
+        &&verifier_setup,
+    )
+    .map_err(|e| JsError::new(&format!("{e}")))
+}
@@ -3,22 +3,36 @@
 use ark_std::test_rng;
 use curve25519_dalek::RistrettoPoint;
 #[cfg(feature = "blitzar")]
-use proof_of_sql::base::commitment::InnerProductProof;
+use proof_of_sql::base::commitment::{InnerProductProof, TableCommitment};
 use proof_of_sql::{
     base::{
-        database::{owned_table_utility::*, OwnedTable, OwnedTableTestAccessor, TestAccessor},
-        scalar::Curve25519Scalar,
+        commitment::{QueryCommitments, QueryCommitmentsExt},
+        database::{
+            owned_table_utility::*, Column, OwnedTable, OwnedTableTestAccessor, TestAccessor,
+        },
+        proof::{Keccak256Transcript, ProofError, Transcript},
+        scalar::{Curve25519Scalar, Scalar},
     },
     proof_primitive::dory::{
         DoryCommitment, DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup,
         DynamicDoryEvaluationProof, ProverSetup, PublicParameters, VerifierSetup,
     },
     sql::{
-        parse::{ConversionError, QueryExpr},
+        parse::{AccessPolicy, ConversionError, QueryExpr},
         postprocessing::apply_postprocessing_steps,
-        proof::{QueryError, QueryProof},
+        proof::{
+            estimate_proof_cost, expected_sumcheck_degree, expected_sumcheck_shape,
+            query_proof_mutation, verify_serialized, ProofEnvelope, ProofEnvelopeDecodeError,
+            ProofPlan, ProvableQueryResult, ProveOptions, QueryError, QueryProof,
+            QueryProofDecodeError, QueryProofReadLimits, QueryProofSummary,
+            SerializedVerificationError, SumcheckShape, VerifyOptions, VerifyTrace,
+            DEFAULT_QUERY_PROOF_READ_LIMITS, PROOF_ENVELOPE_ENCODING_VERSION,
+            QUERY_PROOF_ENCODING_VERSION,
+        },
     },
 };
+use tiny_keccak::{Hasher, Keccak};
+use zerocopy::{AsBytes, FromBytes};
 
 #[test]
 #[cfg(feature = "blitzar")]
@@ -351,6 +365,96 @@ fn we_can_prove_a_query_with_arithmetic_in_where_clause_with_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
+#[test]
+fn we_can_prove_a_query_with_a_product_in_the_result_and_where_clause_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("price", [10, 3, 25, -40, 7]),
+            int("quantity", [11, 30, 4, -3, 2]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::<DoryCommitment>::try_new(
+        "SELECT price * quantity AS total FROM table WHERE price * quantity > 100"
+            .parse()
+            .unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    let transformed_result =
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap();
+    let expected_result = owned_table([bigint("total", [110, 120])]);
+    assert_eq!(transformed_result, expected_result);
+}
+
+#[test]
+fn we_cannot_prove_a_product_that_overflows_its_type_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("price", [i64::MAX, 3]), int("quantity", [2, 40])]),
+        0,
+    );
+    let query = QueryExpr::<DoryCommitment>::try_new(
+        "SELECT price * quantity AS total FROM table WHERE price * quantity > 100"
+            .parse()
+            .unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    assert!(matches!(
+        proof.verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        ),
+        Err(QueryError::Overflow)
+    ));
+
+    // The product is exact, so a wrapped value that fits the type is not accepted either
+    let tampered_result =
+        ProvableQueryResult::new(2, &[Column::<Curve25519Scalar>::BigInt(&[-2, 120])]);
+    assert!(matches!(
+        proof.verify(
+            query.proof_expr(),
+            &accessor,
+            &tampered_result,
+            &dory_verifier_setup,
+        ),
+        Err(QueryError::ProofError { .. })
+    ));
+}
+
 #[test]
 #[cfg(feature = "blitzar")]
 fn we_can_prove_a_basic_equality_with_out_of_order_results_with_curve25519() {
@@ -564,6 +668,67 @@ fn we_can_prove_a_minimal_group_by_query_with_curve25519() {
     assert_eq!(transformed_result, expected_result);
 }
 
+#[test]
+fn we_can_prove_a_group_by_query_with_max_and_min_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 1, 2, 3, 2, 2, 3]),
+            bigint("b", [1, -7, 4, 2, 3, 9, i64::MIN]),
+            int128("c", [-2, 2, 1, 0, 1, -1, i128::MAX]),
+        ]),
+        0,
+    );
+    let verify = |sql: &str| {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table
+    };
+    assert_eq!(
+        verify("SELECT a, sum(b) as s, max(b) as mx, min(c) as mn, count(*) as n FROM table WHERE c >= 0 group by a"),
+        owned_table([
+            bigint("a", [1, 2, 3]),
+            bigint("s", [-7, 7, 2 + i64::MIN]),
+            bigint("mx", [-7, 4, 2]),
+            int128("mn", [2, 1, 0]),
+            bigint("n", [1, 2, 2]),
+        ])
+    );
+    assert_eq!(
+        verify("SELECT max(c) as mx, min(b) as mn, count(*) as n FROM table WHERE a <> 1"),
+        owned_table([
+            int128("mx", [i128::MAX]),
+            bigint("mn", [i64::MIN]),
+            bigint("n", [5]),
+        ])
+    );
+    assert_eq!(
+        verify("SELECT max(b) as mx, count(*) as n FROM table WHERE a = 4"),
+        owned_table([bigint("mx", [0]), bigint("n", [0])])
+    );
+}
+
 #[test]
 #[cfg(feature = "blitzar")]
 fn we_can_prove_a_basic_group_by_query_with_curve25519() {
@@ -787,32 +952,49 @@ fn we_can_prove_a_basic_group_by_query_with_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
-// Overflow checks
 #[test]
-#[cfg(feature = "blitzar")]
-fn we_can_prove_a_query_with_overflow_with_curve25519() {
-    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+fn we_can_prove_a_between_filter_query_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
     accessor.add_table(
         "sxt.table".parse().unwrap(),
-        owned_table([smallint("a", [i16::MAX]), smallint("b", [1_i16])]),
+        owned_table([
+            bigint("a", [1, 2, 3, 4, 5]),
+            varchar("b", ["a", "b", "c", "d", "e"]),
+        ]),
         0,
     );
     let query = QueryExpr::try_new(
-        "SELECT a + b as c from table".parse().unwrap(),
+        "SELECT b FROM table WHERE a BETWEEN 2 AND 4"
+            .parse()
+            .unwrap(),
         "sxt".parse().unwrap(),
         &accessor,
     )
     .unwrap();
     let (proof, serialized_result) =
-        QueryProof::<InnerProductProof>::new(query.proof_expr(), &accessor, &());
-    assert!(matches!(
-        proof.verify(query.proof_expr(), &accessor, &serialized_result, &()),
-        Err(QueryError::Overflow)
-    ));
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    let expected_result = owned_table([varchar("b", ["b", "c", "d"])]);
+    assert_eq!(owned_table_result, expected_result);
 }
 
 #[test]
-fn we_can_prove_a_query_with_overflow_with_dory() {
+fn we_can_prove_a_count_query_without_group_by_with_dory() {
     let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
     let prover_setup = ProverSetup::from(&public_parameters);
     let verifier_setup = VerifierSetup::from(&public_parameters);
@@ -823,43 +1005,171 @@ fn we_can_prove_a_query_with_overflow_with_dory() {
         OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
     accessor.add_table(
         "sxt.table".parse().unwrap(),
-        owned_table([bigint("a", [i64::MIN]), smallint("b", [1_i16])]),
+        owned_table([bigint("a", [1, 2, 3, 2]), bigint("b", [5, 6, 7, 8])]),
+        0,
+    );
+    let prove_and_verify = |sql: &str| {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table
+    };
+    assert_eq!(
+        prove_and_verify("SELECT COUNT(*) AS c FROM table WHERE a = 2"),
+        owned_table([bigint("c", [2])])
+    );
+    // Without GROUP BY there is always exactly one row, even when no rows match.
+    assert_eq!(
+        prove_and_verify("SELECT COUNT(*) AS c FROM table WHERE a = 7"),
+        owned_table([bigint("c", [0])])
+    );
+}
+
+#[test]
+fn we_can_prove_a_count_distinct_query_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 2, 2, 1]),
+            bigint("b", [5, 6, 7, 6, 5]),
+            varchar("c", ["x", "y", "x", "y", "z"]),
+        ]),
+        0,
+    );
+    let prove_and_verify = |sql: &str| {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap()
+    };
+    assert_eq!(
+        prove_and_verify("SELECT COUNT(DISTINCT b) AS n FROM table WHERE a = 2"),
+        owned_table([bigint("n", [2])])
+    );
+    assert_eq!(
+        prove_and_verify("SELECT COUNT(DISTINCT c) AS n FROM table"),
+        owned_table([bigint("n", [3])])
+    );
+    // Without GROUP BY there is always exactly one row, even when no rows match.
+    assert_eq!(
+        prove_and_verify("SELECT COUNT(DISTINCT b) AS n, SUM(b) AS s FROM table WHERE a = 7"),
+        owned_table([bigint("n", [0]), bigint("s", [0])])
+    );
+    assert_eq!(
+        prove_and_verify(
+            "SELECT a, COUNT(DISTINCT c) AS n, SUM(b) AS s, COUNT(*) AS m FROM table GROUP BY a"
+        ),
+        owned_table([
+            bigint("a", [1, 2]),
+            bigint("n", [2, 2]),
+            bigint("s", [10, 19]),
+            bigint("m", [2, 3]),
+        ])
+    );
+}
+
+#[test]
+fn we_cannot_prove_a_count_distinct_query_with_an_extra_value_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 2, 2]), bigint("b", [5, 6, 7, 6])]),
         0,
     );
     let query = QueryExpr::try_new(
-        "SELECT a - b as c from table".parse().unwrap(),
+        "SELECT COUNT(DISTINCT b) AS n FROM table WHERE a = 2"
+            .parse()
+            .unwrap(),
         "sxt".parse().unwrap(),
         &accessor,
     )
     .unwrap();
     let (proof, serialized_result) =
         QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
-    assert!(matches!(
-        proof.verify(
+    assert!(proof
+        .verify(
             query.proof_expr(),
             &accessor,
             &serialized_result,
-            &dory_verifier_setup
-        ),
-        Err(QueryError::Overflow)
-    ));
+            &dory_verifier_setup,
+        )
+        .is_ok());
+
+    // A distinct value that no row has would be a group of no rows
+    let tampered_result = ProvableQueryResult::new(
+        3,
+        &[
+            Column::<Curve25519Scalar>::BigInt(&[6, 7, 9]),
+            Column::BigInt(&[2, 1, 0]),
+        ],
+    );
+    assert!(proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &tampered_result,
+            &dory_verifier_setup,
+        )
+        .is_err());
 }
 
 #[test]
-#[cfg(feature = "blitzar")]
-fn we_can_perform_arithmetic_and_conditional_operations_on_tinyint() {
-    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+fn we_can_prove_a_count_query_without_group_by_on_an_empty_table_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
     accessor.add_table(
         "sxt.table".parse().unwrap(),
-        owned_table([
-            tinyint("a", [3_i8, 5, 2, 1]),
-            tinyint("b", [2_i8, 1, 3, 4]),
-            tinyint("c", [1_i8, 4, 5, 2]),
-        ]),
+        owned_table([bigint("a", [0; 0]), bigint("b", [0; 0])]),
         0,
     );
     let query = QueryExpr::try_new(
-        "SELECT a*b+b+c as result FROM table WHERE a>b OR c=4"
+        "SELECT SUM(b) AS s, MAX(b) AS mx, COUNT(*) AS c FROM table WHERE a = 2"
             .parse()
             .unwrap(),
         "sxt".parse().unwrap(),
@@ -867,11 +1177,1682 @@ fn we_can_perform_arithmetic_and_conditional_operations_on_tinyint() {
     )
     .unwrap();
     let (proof, serialized_result) =
-        QueryProof::<InnerProductProof>::new(query.proof_expr(), &accessor, &());
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
     let owned_table_result = proof
-        .verify(query.proof_expr(), &accessor, &serialized_result, &())
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
         .unwrap()
         .table;
-    let expected_result = owned_table([tinyint("result", [9_i8, 10])]);
+    let expected_result = owned_table([bigint("s", [0]), bigint("mx", [0]), bigint("c", [0])]);
     assert_eq!(owned_table_result, expected_result);
+
+    // The proof does not bind the MAX of no rows, so the verifier insists on zero
+    let tampered_result = ProvableQueryResult::new(
+        1,
+        &[
+            Column::<Curve25519Scalar>::BigInt(&[0]),
+            Column::BigInt(&[5]),
+            Column::BigInt(&[0]),
+        ],
+    );
+    assert!(proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &tampered_result,
+            &dory_verifier_setup,
+        )
+        .is_err());
+}
+
+#[test]
+fn we_can_prove_a_count_query_without_group_by_on_an_empty_table_with_dynamic_dory() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(&prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [0; 0]), bigint("b", [0; 0])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT SUM(b) AS s, MIN(b) AS mn, COUNT(*) AS c FROM table WHERE a = 2"
+            .parse()
+            .unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) = QueryProof::<DynamicDoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &&prover_setup,
+    );
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &&verifier_setup,
+        )
+        .unwrap()
+        .table;
+    let expected_result = owned_table([bigint("s", [0]), bigint("mn", [0]), bigint("c", [0])]);
+    assert_eq!(owned_table_result, expected_result);
+}
+
+#[test]
+fn we_can_prove_a_query_restricted_by_an_access_policy_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 4, 5]),
+            boolean("visible", [true, false, true, false, true]),
+        ]),
+        0,
+    );
+    let access_policy = AccessPolicy::new()
+        .with_permitted_rows("sxt.table".parse().unwrap(), "visible".parse().unwrap());
+    let plan = |sql: &str, access_policy: &AccessPolicy| {
+        QueryExpr::try_new_with_access_policy(
+            sql.parse().unwrap(),
+            "sxt".parse().unwrap(),
+            &accessor,
+            access_policy,
+        )
+        .unwrap()
+    };
+    let prove_and_verify = |sql: &str| {
+        let query = plan(sql, &access_policy);
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap()
+    };
+    assert_eq!(
+        prove_and_verify("SELECT a FROM table"),
+        owned_table([bigint("a", [1, 3, 5])])
+    );
+    assert_eq!(
+        prove_and_verify("SELECT a FROM table WHERE a > 1"),
+        owned_table([bigint("a", [3, 5])])
+    );
+    assert_eq!(
+        prove_and_verify("SELECT COUNT(*) AS c FROM table"),
+        owned_table([bigint("c", [3])])
+    );
+
+    // A proof over every row does not verify against the restricted query.
+    let unrestricted_query = plan("SELECT a FROM table", &AccessPolicy::new());
+    let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+        unrestricted_query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    );
+    assert!(proof
+        .verify(
+            plan("SELECT a FROM table", &access_policy).proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .is_err());
+}
+
+#[test]
+fn we_can_prove_a_query_with_redundant_boolean_literals_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 2]),
+            boolean("b", [true, false, true, true]),
+        ]),
+        0,
+    );
+    let prove_and_verify = |sql: &str| {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table
+    };
+    let expected_result = owned_table([bigint("a", [1, 3, 2])]);
+    assert_eq!(
+        prove_and_verify("SELECT a FROM table WHERE b"),
+        expected_result
+    );
+    assert_eq!(
+        prove_and_verify("SELECT a FROM table WHERE (b OR false) AND NOT false"),
+        expected_result
+    );
+    assert_eq!(
+        prove_and_verify("SELECT a FROM table WHERE b AND false"),
+        owned_table([bigint("a", [0; 0])])
+    );
+}
+
+#[test]
+fn we_can_prove_a_query_with_an_in_list_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 4, 5]), bigint("b", [7, 0, 3, 1, 2])]),
+        0,
+    );
+    let prove_and_verify = |sql: &str| {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table
+    };
+    assert_eq!(
+        prove_and_verify("SELECT a FROM table WHERE b IN (1, 3, 7)"),
+        owned_table([bigint("a", [1, 3, 4])])
+    );
+    assert_eq!(
+        prove_and_verify("SELECT a FROM table WHERE NOT (b IN (1, 3, 7))"),
+        owned_table([bigint("a", [2, 5])])
+    );
+}
+
+#[test]
+fn we_only_include_a_repeated_column_in_the_proof_once_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 5]),
+            bigint("b", [3, 2, 1, 4]),
+            bigint("c", [0, 5, 5, 1]),
+        ]),
+        0,
+    );
+    let prove_and_verify = |sql: &str| {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let table = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        (proof, table)
+    };
+    let (distinct_proof, distinct_table) =
+        prove_and_verify("SELECT a FROM table WHERE b = 3 OR c = 5");
+    assert_eq!(distinct_table, owned_table([bigint("a", [1, 2, 3])]));
+    let (repeated_proof, repeated_table) =
+        prove_and_verify("SELECT a FROM table WHERE a = 3 OR a = 5");
+    assert_eq!(repeated_table, owned_table([bigint("a", [3, 5])]));
+
+    // Both queries have the same shape, but `a` is committed to once instead of `a`, `b` and `c`.
+    assert_eq!(
+        distinct_proof.pcs_proof_evaluations.len() - repeated_proof.pcs_proof_evaluations.len(),
+        2
+    );
+    assert!(
+        repeated_proof
+            .serialized_size_breakdown()
+            .pcs_proof_evaluations
+            < distinct_proof
+                .serialized_size_breakdown()
+                .pcs_proof_evaluations
+    );
+}
+
+#[test]
+fn we_can_prove_a_query_with_modulo_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("id", [0, 7, 100, -7, 300, 42]),
+            int("b", [5, -5, 0, 9, 10, 11]),
+            int128("c", [i128::MIN, i128::MAX, -1, 0, 1, i128::from(i64::MAX)]),
+        ]),
+        0,
+    );
+    let prove_and_verify = |sql: &str| {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap()
+    };
+    assert_eq!(
+        prove_and_verify("SELECT id FROM table WHERE id % 100 = 0"),
+        owned_table([bigint("id", [0, 100, 300])])
+    );
+    assert_eq!(
+        prove_and_verify("SELECT id % 3 AS r, b % 4 AS s FROM table"),
+        owned_table([
+            bigint("r", [0, 1, 1, 2, 0, 0]),
+            bigint("s", [1, 3, 0, 1, 2, 3]),
+        ])
+    );
+    let modulus = i128::from(i64::MAX);
+    assert_eq!(
+        prove_and_verify("SELECT c % 9223372036854775807 AS t FROM table"),
+        owned_table([bigint(
+            "t",
+            [i128::MIN, i128::MAX, -1, 0, 1, modulus]
+                .map(|c| i64::try_from(c.rem_euclid(modulus)).unwrap())
+        )])
+    );
+}
+
+#[test]
+fn we_can_prove_a_query_with_case_when_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("x", [-3, 0, 4, 7, -1, 2]),
+            int("y", [10, 20, 30, 40, 50, 60]),
+        ]),
+        0,
+    );
+    let prove_and_verify = |sql: &str| {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap()
+    };
+    assert_eq!(
+        prove_and_verify("SELECT CASE WHEN x > 0 THEN x ELSE 0 END AS p FROM table"),
+        owned_table([bigint("p", [0, 0, 4, 7, 0, 2])])
+    );
+    assert_eq!(
+        prove_and_verify(
+            "SELECT y, CASE WHEN x >= 2 THEN y * x ELSE 0 - y END AS z FROM table \
+             WHERE CASE WHEN x < 0 THEN y ELSE x END <= 20"
+        ),
+        owned_table([
+            int("y", [10, 20, 30, 40, 60]),
+            bigint("z", [-10, -20, 120, 280, 120])
+        ])
+    );
+}
+
+#[test]
+fn we_can_predict_the_sumcheck_shape_of_a_proof_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, -2, 3, 4, -5, 6])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a FROM table WHERE a >= 0".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let shape = expected_sumcheck_shape(query.proof_expr(), &accessor).unwrap();
+    assert_eq!(
+        shape,
+        SumcheckShape {
+            degree: 3,
+            rounds: 3
+        }
+    );
+    assert_eq!(
+        expected_sumcheck_degree(query.proof_expr(), &accessor).unwrap(),
+        3
+    );
+    let (proof, _) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    assert_eq!(
+        proof.summary().sumcheck_coefficients,
+        shape.rounds * (shape.degree + 1)
+    );
+}
+
+#[test]
+fn we_can_estimate_the_cost_of_a_proof_with_dory() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 4);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.small".parse().unwrap(),
+        owned_table([bigint("a", [1, -2, 3, 4, -5, 6])]),
+        0,
+    );
+    accessor.add_table(
+        "sxt.large".parse().unwrap(),
+        owned_table([bigint("a", -6..6)]),
+        0,
+    );
+    let query = |sql: &str| {
+        QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap()
+    };
+    let estimate = |query: &QueryExpr<DoryCommitment>| {
+        estimate_proof_cost(query.proof_expr(), &accessor).unwrap()
+    };
+
+    let filter = query("SELECT a FROM small WHERE a >= 0");
+    let cost = estimate(&filter);
+    assert_eq!(cost.table_length, 6);
+    assert_eq!(
+        cost.sumcheck,
+        expected_sumcheck_shape(filter.proof_expr(), &accessor).unwrap()
+    );
+    let (proof, _) =
+        QueryProof::<DoryEvaluationProof>::new(filter.proof_expr(), &accessor, &dory_prover_setup);
+    let summary = proof.summary();
+    assert!(cost.commitments >= summary.commitments);
+    assert!(cost.pcs_proof_mles >= summary.pcs_proof_evaluations);
+
+    // The estimate grows with both the table and the query.
+    assert!(
+        estimate(&query("SELECT a FROM large WHERE a >= 0")).field_operations
+            > cost.field_operations
+    );
+    assert!(
+        estimate(&query("SELECT a FROM small WHERE a >= 0 AND a * a <= 16")).field_operations
+            > cost.field_operations
+    );
+    assert!(estimate(&query("SELECT a FROM small")).field_operations < cost.field_operations);
+}
+
+#[test]
+fn we_can_verify_a_subset_of_the_result_columns_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 2]),
+            varchar("b", ["x", "y", "z", "w"]),
+            boolean("c", [true, false, true, true]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a, b, c FROM table WHERE a = 2".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let verify_projected = |columns: &[usize]| {
+        proof.verify_projected(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+            columns,
+        )
+    };
+
+    let full = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap();
+    let projected = verify_projected(&[2, 1]).unwrap();
+    assert_eq!(
+        projected.table,
+        owned_table([boolean("c", [false, true]), varchar("b", ["y", "w"])])
+    );
+    assert_eq!(projected.verification_hash, full.verification_hash);
+    assert_eq!(verify_projected(&[]).unwrap().table.num_columns(), 0);
+    assert!(matches!(
+        verify_projected(&[0, 3]),
+        Err(QueryError::ColumnIndexOutOfRange {
+            index: 3,
+            column_count: 3
+        })
+    ));
+    assert!(matches!(
+        verify_projected(&[1, 1]),
+        Err(QueryError::DuplicateColumnIndex { index: 1 })
+    ));
+
+    let (_, other_result) = QueryProof::<DoryEvaluationProof>::new(
+        QueryExpr::try_new(
+            "SELECT a, b, c FROM table WHERE a = 3".parse().unwrap(),
+            "sxt".parse().unwrap(),
+            &accessor,
+        )
+        .unwrap()
+        .proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    );
+    // A result that does not match the proof is rejected no matter which columns are requested.
+    assert!(proof
+        .verify_projected(
+            query.proof_expr(),
+            &accessor,
+            &other_result,
+            &dory_verifier_setup,
+            &[0],
+        )
+        .is_err());
+}
+
+#[test]
+fn we_can_trace_the_verification_of_a_query_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 2]), bigint("b", [4, 5, 6, 7])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT b FROM table WHERE a = 2".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let verify_with_trace = |proof: &QueryProof<DoryEvaluationProof>| {
+        let mut trace = VerifyTrace::default();
+        let result = proof.verify_with_options::<Keccak256Transcript>(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+            VerifyOptions {
+                trace: Some(&mut trace),
+                ..VerifyOptions::default()
+            },
+        );
+        (result, trace)
+    };
+
+    let (result, trace) = verify_with_trace(&proof);
+    assert_eq!(result.unwrap().table, owned_table([bigint("b", [5, 7])]));
+    assert_eq!(trace.evaluation_point.len(), 2);
+    assert!(trace.expected_sumcheck_evaluation.is_some());
+    assert_eq!(
+        trace.sumcheck_evaluation,
+        trace.expected_sumcheck_evaluation
+    );
+    assert_eq!(trace.verifier_result_evaluations.len(), 1);
+    assert_eq!(trace.verifier_result_evaluations, trace.result_evaluations);
+    assert!(trace.folded_pcs_proof_commitment.is_some());
+    assert!(trace.folded_pcs_proof_evaluation.is_some());
+
+    // The sumcheck subclaim is unaffected by a corrupted evaluation, but a later check diverges.
+    let corrupted_proof = query_proof_mutation::perturb_pcs_proof_evaluation(proof.clone(), 0);
+    let (result, corrupted_trace) = verify_with_trace(&corrupted_proof);
+    assert!(matches!(result, Err(QueryError::ProofError { .. })));
+    assert_eq!(corrupted_trace.evaluation_point, trace.evaluation_point);
+    assert_eq!(
+        corrupted_trace.expected_sumcheck_evaluation,
+        trace.expected_sumcheck_evaluation
+    );
+    assert!(
+        corrupted_trace.sumcheck_evaluation != corrupted_trace.expected_sumcheck_evaluation
+            || corrupted_trace.verifier_result_evaluations != corrupted_trace.result_evaluations
+    );
+}
+
+/// A transcript that only differs from [`Keccak256Transcript`] by a domain separator
+struct DomainSeparatedTranscript(Keccak256Transcript);
+
+impl Transcript for DomainSeparatedTranscript {
+    fn new() -> Self {
+        let mut transcript = Keccak256Transcript::new();
+        transcript.extend_as_le_from_refs([b"domain separated".as_slice()]);
+        Self(transcript)
+    }
+    fn extend_as_be<M: FromBytes + AsBytes>(&mut self, messages: impl IntoIterator<Item = M>) {
+        self.0.extend_as_be(messages);
+    }
+    fn extend_as_le_from_refs<'a, M: AsBytes + 'a + ?Sized>(
+        &mut self,
+        messages: impl IntoIterator<Item = &'a M>,
+    ) {
+        self.0.extend_as_le_from_refs(messages);
+    }
+    fn extend_scalars_as_be<'a, S: Scalar + 'a>(
+        &mut self,
+        messages: impl IntoIterator<Item = &'a S>,
+    ) {
+        self.0.extend_scalars_as_be(messages);
+    }
+    fn scalar_challenge_as_be<S: Scalar>(&mut self) -> S {
+        self.0.scalar_challenge_as_be()
+    }
+    fn challenge_as_le(&mut self) -> [u8; 32] {
+        self.0.challenge_as_le()
+    }
+}
+
+#[test]
+fn we_can_prove_and_verify_a_query_with_a_custom_transcript_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 2]), bigint("b", [4, 5, 6, 7])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT b FROM table WHERE a = 2".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new_with_options::<DomainSeparatedTranscript>(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+            ProveOptions::default(),
+        );
+    let owned_table_result = proof
+        .verify_with_options::<DomainSeparatedTranscript>(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+            VerifyOptions::default(),
+        )
+        .unwrap()
+        .table;
+    assert_eq!(owned_table_result, owned_table([bigint("b", [5, 7])]));
+
+    // The prover and verifier must use the same transcript.
+    assert!(matches!(
+        proof.verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup
+        ),
+        Err(QueryError::ProofError { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_verify_a_query_that_exceeds_the_sumcheck_budget_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 2, 5, 6, 2, 8])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a FROM table WHERE a = 2".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let verify = |max_sumcheck_variables| {
+        proof.verify_with_options::<Keccak256Transcript>(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+            VerifyOptions {
+                max_sumcheck_variables,
+                ..VerifyOptions::default()
+            },
+        )
+    };
+
+    // A table of 8 rows needs 3 sumcheck variables.
+    assert_eq!(
+        verify(3).unwrap().table,
+        owned_table([bigint("a", [2, 2, 2])])
+    );
+    assert!(matches!(
+        verify(2),
+        Err(QueryError::ProofError {
+            source: ProofError::ExceedsBudget { max: 2, actual: 3 }
+        })
+    ));
+}
+
+#[test]
+fn we_can_verify_a_fully_serialized_query_with_dynamic_dory() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(&prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 2]),
+            varchar("b", ["x", "y", "z", "w"]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT b FROM table WHERE a = 2".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) = QueryProof::<DynamicDoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &&prover_setup,
+    );
+    let commitments = QueryCommitments::from_accessor_with_max_bounds(
+        query.proof_expr().get_column_references(),
+        &accessor,
+    );
+    let proof_bytes = proof.to_bytes();
+    let plan_bytes = postcard::to_allocvec(query.proof_expr()).unwrap();
+    let commitments_bytes = postcard::to_allocvec(&commitments).unwrap();
+    let result_bytes = postcard::to_allocvec(&serialized_result).unwrap();
+    let verify = |proof_bytes: &[u8], result_bytes: &[u8]| {
+        verify_serialized::<DynamicDoryEvaluationProof>(
+            proof_bytes,
+            &plan_bytes,
+            &commitments_bytes,
+            result_bytes,
+            &&verifier_setup,
+        )
+    };
+
+    let expected_data = proof
+        .verify(
+            query.proof_expr(),
+            &commitments,
+            &serialized_result,
+            &&verifier_setup,
+        )
+        .unwrap();
+    assert_eq!(expected_data.table, owned_table([varchar("b", ["y", "w"])]));
+    assert_eq!(
+        verify(&proof_bytes, &result_bytes).unwrap(),
+        postcard::to_allocvec(&expected_data).unwrap()
+    );
+
+    assert!(matches!(
+        verify(&proof_bytes[1..], &result_bytes),
+        Err(SerializedVerificationError::InvalidProof { .. })
+    ));
+    assert!(matches!(
+        verify(&proof_bytes, &[]),
+        Err(SerializedVerificationError::Deserialization {
+            input: "query result",
+            ..
+        })
+    ));
+    let (_, other_result) = QueryProof::<DynamicDoryEvaluationProof>::new(
+        QueryExpr::try_new(
+            "SELECT b FROM table WHERE a = 3".parse().unwrap(),
+            "sxt".parse().unwrap(),
+            &accessor,
+        )
+        .unwrap()
+        .proof_expr(),
+        &accessor,
+        &&prover_setup,
+    );
+    assert!(matches!(
+        verify(&proof_bytes, &postcard::to_allocvec(&other_result).unwrap()),
+        Err(SerializedVerificationError::Verification { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_verify_a_serialized_query_without_commitments_for_it_with_dynamic_dory() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(&prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 2]),
+            varchar("b", ["x", "y", "z", "w"]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT b FROM table WHERE a = 2".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) = QueryProof::<DynamicDoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &&prover_setup,
+    );
+    let proof_bytes = proof.to_bytes();
+    let plan_bytes = postcard::to_allocvec(query.proof_expr()).unwrap();
+    let result_bytes = postcard::to_allocvec(&serialized_result).unwrap();
+
+    // Commitments that do not cover the query are an error rather than a panic.
+    let verify_with_commitments = |commitments: &QueryCommitments<_>| {
+        verify_serialized::<DynamicDoryEvaluationProof>(
+            &proof_bytes,
+            &plan_bytes,
+            &postcard::to_allocvec(commitments).unwrap(),
+            &result_bytes,
+            &&verifier_setup,
+        )
+    };
+    assert!(matches!(
+        verify_with_commitments(&QueryCommitments::default()),
+        Err(SerializedVerificationError::Verification {
+            source: QueryError::ProofError {
+                source: ProofError::MissingTableCommitment { .. }
+            }
+        })
+    ));
+    let column_b_only = QueryCommitments::from_accessor_with_max_bounds(
+        query
+            .proof_expr()
+            .get_column_references()
+            .into_iter()
+            .filter(|column| column.column_id().as_str() == "b"),
+        &accessor,
+    );
+    assert!(matches!(
+        verify_with_commitments(&column_b_only),
+        Err(SerializedVerificationError::Verification {
+            source: QueryError::ProofError {
+                source: ProofError::MissingColumnCommitment { .. }
+            }
+        })
+    ));
+}
+
+#[test]
+#[cfg(feature = "blitzar")]
+fn we_can_prove_a_query_over_a_window_of_a_larger_table_with_curve25519() {
+    let table_ref = "sxt.table".parse().unwrap();
+    let table =
+        |a: &[i64], b: &[&str]| owned_table([bigint("a", a.to_vec()), varchar("b", b.to_vec())]);
+    // The verifier only trusts commitments to the whole table and to the rows before the window.
+    let full_commitment = TableCommitment::<RistrettoPoint>::from_owned_table_with_offset(
+        &table(&[2, 1, 2, 3, 2, 2], &["u", "v", "x", "y", "z", "w"]),
+        0,
+        &(),
+    );
+    let prefix_commitment = TableCommitment::<RistrettoPoint>::from_owned_table_with_offset(
+        &table(&[2, 1], &["u", "v"]),
+        0,
+        &(),
+    );
+    let window_commitment = full_commitment.try_sub(prefix_commitment).unwrap();
+    assert_eq!(window_commitment.range(), &(2..6));
+    let commitments = QueryCommitments::from_iter([(table_ref, window_commitment)]);
+
+    // The prover only needs the rows in the window, placed at the window's offset.
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(table_ref, table(&[2, 3, 2, 2], &["x", "y", "z", "w"]), 2);
+    let query = QueryExpr::try_new(
+        "SELECT b FROM table WHERE a = 2".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &commitments,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<InnerProductProof>::new(query.proof_expr(), &accessor, &());
+    let owned_table_result = proof
+        .verify(query.proof_expr(), &commitments, &serialized_result, &())
+        .unwrap()
+        .table;
+    assert_eq!(
+        owned_table_result,
+        owned_table([varchar("b", ["x", "z", "w"])])
+    );
+
+    // A window that is not where the prover claims it is fails to verify.
+    let mut misplaced_accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    misplaced_accessor.add_table(table_ref, table(&[2, 3, 2, 2], &["x", "y", "z", "w"]), 1);
+    let (proof, serialized_result) =
+        QueryProof::<InnerProductProof>::new(query.proof_expr(), &misplaced_accessor, &());
+    assert!(proof
+        .verify(query.proof_expr(), &commitments, &serialized_result, &())
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "blitzar")]
+fn we_can_chain_a_proof_over_the_committed_result_of_a_verified_query_with_curve25519() {
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 2, 5]), bigint("b", [7, 8, 9, 10, 11])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a, b FROM table WHERE a >= 2".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<InnerProductProof>::new(query.proof_expr(), &accessor, &());
+    let derived_table = proof
+        .verify(query.proof_expr(), &accessor, &serialized_result, &())
+        .unwrap()
+        .table;
+
+    // The verified result is committed by the verifier itself, so the commitments are trusted.
+    let derived_table_ref = "sxt.derived".parse().unwrap();
+    let commitments = QueryCommitments::from_iter([(
+        derived_table_ref,
+        TableCommitment::<RistrettoPoint>::from_owned_table_with_offset(&derived_table, 0, &()),
+    )]);
+    let mut derived_accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    derived_accessor.add_table(derived_table_ref, derived_table, 0);
+    let query = QueryExpr::try_new(
+        "SELECT b FROM derived WHERE a = 2".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &commitments,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<InnerProductProof>::new(query.proof_expr(), &derived_accessor, &());
+    let owned_table_result = proof
+        .verify(query.proof_expr(), &commitments, &serialized_result, &())
+        .unwrap()
+        .table;
+    assert_eq!(owned_table_result, owned_table([bigint("b", [8, 10])]));
+}
+
+// Overflow checks
+#[test]
+#[cfg(feature = "blitzar")]
+fn we_can_prove_a_query_with_overflow_with_curve25519() {
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([smallint("a", [i16::MAX]), smallint("b", [1_i16])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a + b as c from table".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<InnerProductProof>::new(query.proof_expr(), &accessor, &());
+    assert!(matches!(
+        proof.verify(query.proof_expr(), &accessor, &serialized_result, &()),
+        Err(QueryError::Overflow)
+    ));
+}
+
+#[test]
+fn we_can_prove_a_query_with_overflow_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [i64::MIN]), smallint("b", [1_i16])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a - b as c from table".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    assert!(matches!(
+        proof.verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup
+        ),
+        Err(QueryError::Overflow)
+    ));
+}
+
+#[test]
+#[cfg(feature = "blitzar")]
+fn we_can_perform_arithmetic_and_conditional_operations_on_tinyint() {
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            tinyint("a", [3_i8, 5, 2, 1]),
+            tinyint("b", [2_i8, 1, 3, 4]),
+            tinyint("c", [1_i8, 4, 5, 2]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a*b+b+c as result FROM table WHERE a>b OR c=4"
+            .parse()
+            .unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<InnerProductProof>::new(query.proof_expr(), &accessor, &());
+    let owned_table_result = proof
+        .verify(query.proof_expr(), &accessor, &serialized_result, &())
+        .unwrap()
+        .table;
+    let expected_result = owned_table([tinyint("result", [9_i8, 10])]);
+    assert_eq!(owned_table_result, expected_result);
+}
+
+#[test]
+fn we_can_compute_the_serialized_size_of_a_proof_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 2]), bigint("b", [1, 0, 1, 1])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a FROM table WHERE b = 1".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, _serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let serialized_proof = postcard::to_allocvec(&proof).unwrap();
+    assert_eq!(proof.serialized_size(), serialized_proof.len());
+    assert_eq!(
+        proof.serialized_size_breakdown().total(),
+        serialized_proof.len()
+    );
+}
+
+#[test]
+fn we_can_round_trip_a_versioned_proof_encoding_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 2]), bigint("b", [1, 0, 1, 1])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a FROM table WHERE b = 1".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let bytes = proof.to_bytes();
+    assert_eq!(bytes.len(), proof.serialized_size() + 5);
+    assert_eq!(&bytes[..4], b"PSQP");
+    assert_eq!(bytes[4], QUERY_PROOF_ENCODING_VERSION);
+
+    let decoded = QueryProof::<DoryEvaluationProof>::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.to_bytes(), bytes);
+    let owned_table_result = decoded
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    assert_eq!(owned_table_result, owned_table([bigint("a", [1, 3, 2])]));
+
+    let mut wrong_version = bytes.clone();
+    wrong_version[4] += 1;
+    assert!(matches!(
+        QueryProof::<DoryEvaluationProof>::from_bytes(&wrong_version),
+        Err(QueryProofDecodeError::UnsupportedVersion { version, expected })
+            if version == QUERY_PROOF_ENCODING_VERSION + 1 && expected == QUERY_PROOF_ENCODING_VERSION
+    ));
+    assert!(matches!(
+        QueryProof::<DoryEvaluationProof>::from_bytes(&bytes[5..]),
+        Err(QueryProofDecodeError::InvalidMagic)
+    ));
+    assert!(matches!(
+        QueryProof::<DoryEvaluationProof>::from_bytes(&bytes[..4]),
+        Err(QueryProofDecodeError::InvalidMagic)
+    ));
+    assert!(matches!(
+        QueryProof::<DoryEvaluationProof>::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(QueryProofDecodeError::Deserialization { .. })
+    ));
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(matches!(
+        QueryProof::<DoryEvaluationProof>::from_bytes(&trailing),
+        Err(QueryProofDecodeError::TrailingBytes { count: 1 })
+    ));
+}
+
+#[test]
+fn we_can_round_trip_and_verify_a_proof_envelope_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 2]), bigint("b", [1, 0, 1, 1])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a FROM table WHERE b = 1".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let bytes = ProofEnvelope {
+        query,
+        result,
+        proof,
+    }
+    .to_bytes();
+    assert_eq!(&bytes[..4], b"PSQE");
+    assert_eq!(bytes[4], PROOF_ENVELOPE_ENCODING_VERSION);
+
+    let envelope = ProofEnvelope::<DoryEvaluationProof>::from_bytes(&bytes).unwrap();
+    assert_eq!(envelope.to_bytes(), bytes);
+    let owned_table_result = envelope
+        .verify(&accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    assert_eq!(owned_table_result, owned_table([bigint("a", [1, 3, 2])]));
+
+    let mut corrupted = bytes.clone();
+    corrupted[bytes.len() / 2] ^= 1;
+    assert!(matches!(
+        ProofEnvelope::<DoryEvaluationProof>::from_bytes(&corrupted),
+        Err(ProofEnvelopeDecodeError::ChecksumMismatch)
+    ));
+    assert!(matches!(
+        ProofEnvelope::<DoryEvaluationProof>::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(ProofEnvelopeDecodeError::ChecksumMismatch)
+    ));
+    let mut wrong_version = bytes.clone();
+    wrong_version[4] += 1;
+    assert!(matches!(
+        ProofEnvelope::<DoryEvaluationProof>::from_bytes(&wrong_version),
+        Err(ProofEnvelopeDecodeError::UnsupportedVersion { version, expected })
+            if version == PROOF_ENVELOPE_ENCODING_VERSION + 1 && expected == PROOF_ENVELOPE_ENCODING_VERSION
+    ));
+    assert!(matches!(
+        ProofEnvelope::<DoryEvaluationProof>::from_bytes(&bytes[5..]),
+        Err(ProofEnvelopeDecodeError::InvalidMagic)
+    ));
+}
+
+#[test]
+fn we_can_read_a_proof_from_a_stream_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 2]), bigint("b", [1, 0, 1, 1])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a FROM table WHERE b = 1".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let bytes = proof.to_bytes();
+
+    // Two proofs back to back are read one at a time.
+    let stream = [bytes.as_slice(), bytes.as_slice()].concat();
+    let mut reader = stream.as_slice();
+    for _ in 0..2 {
+        let decoded = QueryProof::<DoryEvaluationProof>::read_from(
+            &mut reader,
+            &DEFAULT_QUERY_PROOF_READ_LIMITS,
+        )
+        .unwrap();
+        assert_eq!(decoded.to_bytes(), bytes);
+        let owned_table_result = decoded
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        assert_eq!(owned_table_result, owned_table([bigint("a", [1, 3, 2])]));
+    }
+    assert!(reader.is_empty());
+
+    let read = |bytes: &[u8], limits: &QueryProofReadLimits| {
+        QueryProof::<DoryEvaluationProof>::read_from(bytes, limits)
+    };
+    assert!(matches!(
+        read(&bytes[..bytes.len() - 1], &DEFAULT_QUERY_PROOF_READ_LIMITS),
+        Err(QueryProofDecodeError::Deserialization { .. })
+    ));
+    assert!(matches!(
+        read(&bytes[..3], &DEFAULT_QUERY_PROOF_READ_LIMITS),
+        Err(QueryProofDecodeError::Io { source }) if source.kind() == std::io::ErrorKind::UnexpectedEof
+    ));
+    assert!(matches!(
+        read(b"XXXXX", &DEFAULT_QUERY_PROOF_READ_LIMITS),
+        Err(QueryProofDecodeError::InvalidMagic)
+    ));
+    let limits = QueryProofReadLimits {
+        commitments: proof.summary().commitments - 1,
+        ..DEFAULT_QUERY_PROOF_READ_LIMITS
+    };
+    assert!(matches!(
+        read(&bytes, &limits),
+        Err(QueryProofDecodeError::TooManyComponents {
+            component: "commitments",
+            ..
+        })
+    ));
+
+    // A stream claiming a huge number of bit distributions is rejected before they are read.
+    let mut hostile = bytes[..5].to_vec();
+    hostile.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, 0x0f]);
+    assert!(matches!(
+        read(&hostile, &DEFAULT_QUERY_PROOF_READ_LIMITS),
+        Err(QueryProofDecodeError::TooManyComponents {
+            component: "bit distributions",
+            max: 65_536,
+            actual: 4_294_967_295,
+        })
+    ));
+}
+
+#[test]
+fn we_can_summarize_and_debug_print_a_proof_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 2]), bigint("b", [1, 0, 1, 1])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a FROM table WHERE b = 1".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, _serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let summary = proof.summary();
+    assert_eq!(
+        summary,
+        QueryProofSummary {
+            bit_distributions: 0,
+            commitments: 4,
+            sumcheck_coefficients: summary.sumcheck_coefficients,
+            pcs_proof_evaluations: 6,
+        }
+    );
+    assert!(summary.sumcheck_coefficients > 0);
+
+    let debug = format!("{proof:?}");
+    assert!(debug.starts_with("QueryProof { bit_distributions: 0, commitments: ["));
+    assert!(debug.ends_with(&format!(
+        "], sumcheck_coefficients: {}, pcs_proof_evaluations: 6, .. }}",
+        summary.sumcheck_coefficients
+    )));
+    assert_eq!(debug.matches("..,").count() + 1, 4);
+}
+
+#[test]
+fn we_do_not_commit_to_result_columns_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 2]),
+            bigint("b", [1, 0, 1, 1]),
+            bigint("c", [4, 5, 6, 7]),
+            varchar("d", ["w", "x", "y", "z"]),
+        ]),
+        0,
+    );
+    let prove_and_verify = |sql: &str| {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let table = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        (table, proof.summary())
+    };
+
+    // Each extra result column only adds the opening of its input column.
+    let (narrow_table, narrow) = prove_and_verify("SELECT a FROM table WHERE b = 1");
+    let (wide_table, wide) = prove_and_verify("SELECT a, c, d FROM table WHERE b = 1");
+    assert_eq!(narrow_table, owned_table([bigint("a", [1, 3, 2])]));
+    assert_eq!(
+        wide_table,
+        owned_table([
+            bigint("a", [1, 3, 2]),
+            bigint("c", [4, 6, 7]),
+            varchar("d", ["w", "y", "z"]),
+        ])
+    );
+    assert_eq!(wide.commitments, narrow.commitments);
+    assert_eq!(wide.pcs_proof_evaluations, narrow.pcs_proof_evaluations + 2);
+}
+
+#[test]
+fn we_can_bind_a_proof_to_a_context_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 2]), bigint("b", [1, 0, 1, 1])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a FROM table WHERE b = 1".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new_with_options::<Keccak256Transcript>(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+            ProveOptions {
+                context: b"request-1",
+            },
+        );
+    let verify = |context: &[u8]| {
+        proof.verify_with_options::<Keccak256Transcript>(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+            VerifyOptions {
+                context,
+                ..VerifyOptions::default()
+            },
+        )
+    };
+    assert_eq!(
+        verify(b"request-1").unwrap().table,
+        owned_table([bigint("a", [1, 3, 2])])
+    );
+    assert!(matches!(
+        verify(b"request-2"),
+        Err(QueryError::ProofError { .. })
+    ));
+    assert!(matches!(verify(b""), Err(QueryError::ProofError { .. })));
+    assert!(matches!(
+        proof.verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup
+        ),
+        Err(QueryError::ProofError { .. })
+    ));
+
+    // An empty context is the same as no context.
+    let (proof_without_context, _) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let (proof_with_empty_context, _) =
+        QueryProof::<DoryEvaluationProof>::new_with_options::<Keccak256Transcript>(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+            ProveOptions { context: &[] },
+        );
+    assert_eq!(
+        proof_without_context.to_bytes(),
+        proof_with_empty_context.to_bytes()
+    );
+}
+
+/// Proofs are deterministic, so a fixed setup, table and query always produce the same bytes.
+/// If this test fails, the proof format has changed and the expected hash must be updated deliberately.
+#[test]
+fn we_can_generate_a_byte_stable_proof_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 2]),
+            varchar("b", ["x", "y", "z", "y"]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT b FROM table WHERE a = 2".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let prove = || {
+        let (proof, _serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        postcard::to_allocvec(&proof).unwrap()
+    };
+    let proof_bytes = prove();
+    assert_eq!(proof_bytes, prove());
+
+    let mut proof_hash = [0u8; 32];
+    let mut hasher = Keccak::v256();
+    hasher.update(&proof_bytes);
+    hasher.finalize(&mut proof_hash);
+    assert_eq!(
+        proof_hash,
+        hex_literal("6c0a96e2f7358117ee952b553ccaf0527cdc026bc1e9576d45377f3aaeba81e8")
+    );
+}
+
+#[test]
+fn we_cannot_verify_a_corrupted_proof_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 2]), bigint("b", [5, 6, 7, 8])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT b FROM table WHERE a = 2".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let verification_error = |proof: QueryProof<DoryEvaluationProof>| {
+        let Err(error @ QueryError::ProofError { .. }) = proof.verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        ) else {
+            panic!("the corrupted proof must be rejected by the verifier");
+        };
+        error.to_string()
+    };
+    proof
+        .clone()
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap();
+    assert_eq!(
+        verification_error(query_proof_mutation::remove_commitment(proof.clone())),
+        "Expected 4 intermediate commitments but the proof has 3"
+    );
+    assert_eq!(
+        verification_error(query_proof_mutation::perturb_commitment(proof.clone(), 0)),
+        "Verification error: round evaluation does not match claimed sum"
+    );
+    assert_eq!(
+        verification_error(query_proof_mutation::truncate_sumcheck_proof(proof.clone())),
+        "Verification error: invalid proof size"
+    );
+    assert_eq!(
+        verification_error(query_proof_mutation::perturb_sumcheck_proof(
+            proof.clone(),
+            0
+        )),
+        "Verification error: round evaluation does not match claimed sum"
+    );
+    assert_eq!(
+        verification_error(query_proof_mutation::remove_pcs_proof_evaluation(
+            proof.clone()
+        )),
+        "Expected 6 MLE evaluations but the proof has 5"
+    );
+    assert_eq!(
+        verification_error(query_proof_mutation::perturb_pcs_proof_evaluation(
+            proof.clone(),
+            0
+        )),
+        "Verification error: sumcheck evaluation check failed"
+    );
+}
+
+fn hex_literal(hex: &str) -> [u8; 32] {
+    core::array::from_fn(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap())
 }